@@ -0,0 +1,55 @@
+//! Hashes the sources into `AOC_SOURCE_HASH`, and names the profile and the
+//! enabled features in `AOC_BUILD`, which together identify a build for
+//! [`aoc::cache`] whatever the binary.
+
+use std::path::{Path, PathBuf};
+
+/// FNV-1a, as in `src/util/cache.rs`.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    return bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+}
+
+fn files(dir: &Path, found: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            files(&path, found);
+        } else {
+            found.push(path);
+        }
+    }
+}
+
+fn main() {
+    let mut sources = vec![PathBuf::from("Cargo.toml"), PathBuf::from("Cargo.lock")];
+    files(Path::new("src"), &mut sources);
+    sources.sort();
+
+    let mut hash = 0xcbf29ce484222325;
+    for path in &sources {
+        hash = fnv1a(hash, path.to_string_lossy().as_bytes());
+        hash = fnv1a(hash, &[0]);
+        hash = fnv1a(hash, &std::fs::read(path).unwrap_or_default());
+    }
+
+    // Timings of a debug build say nothing of a release one. `default` adds
+    // no code of its own.
+    let mut build = std::env::vars()
+        .filter_map(|(name, _)| Some(name.strip_prefix("CARGO_FEATURE_")?.to_lowercase()))
+        .filter(|feature| feature != "default")
+        .collect::<Vec<_>>();
+    build.sort();
+    build.insert(0, std::env::var("PROFILE").unwrap_or_default());
+
+    println!("cargo:rustc-env=AOC_SOURCE_HASH={:016x}", hash);
+    println!("cargo:rustc-env=AOC_BUILD={}", build.join("+"));
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+}
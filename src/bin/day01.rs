@@ -1,84 +1,82 @@
-fn parse_input(input: impl AsRef<str>) -> Vec<u32> {
-    let mut calories = input
-        .as_ref()
-        .trim_end()
-        .split("\n\n")
-        .map(|elf| {
-            aoc::parsing::lines_to_vec::<u32>(elf)
-                .unwrap()
-                .into_iter()
-                .sum()
-        })
-        .collect::<Vec<u32>>();
-
-    calories.sort();
-    calories.reverse();
-
-    return calories;
-}
-
-fn solve(calories: &Vec<u32>, top: usize) -> u32 {
-    calories.into_iter().take(top).sum()
-}
-
-fn part1(calories: &Vec<u32>) -> String {
-    return solve(calories, 1).to_string();
-}
-
-fn part2(calories: &Vec<u32>) -> String {
-    return solve(calories, 3).to_string();
+use clap::Parser;
+
+use aoc::config::Args;
+use aoc::runner;
+use aoc::solution::Solution;
+use aoc::y2022::day01::{self, Day01, Query};
+
+/// Day 1 of 2022; with `--stream`, for calorie lists too large to hold in
+/// memory, and with `--top`, `--percentile`, `--histogram` or `--elf`,
+/// reports on the elves instead of solving.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Read the list one line at a time in constant memory, keeping only the
+    /// elves carrying the most
+    #[arg(long, conflicts_with_all = ["top", "percentile", "histogram", "elf"])]
+    stream: bool,
+
+    /// Show the K elves carrying the most and their calories
+    #[arg(long, value_name = "K")]
+    top: Option<usize>,
+
+    /// With `--top`, also show the elves carrying as much as the last one
+    #[arg(long, requires = "top")]
+    ties: bool,
+
+    /// Show the total at percentile P of the totals, from 0 to 100; may be
+    /// given several times
+    #[arg(long, value_name = "P")]
+    percentile: Vec<f64>,
+
+    /// Show how many elves carry totals in each bucket of WIDTH calories
+    #[arg(long, value_name = "WIDTH")]
+    histogram: Option<u32>,
+
+    /// Show the elf numbered N, from 1; may be given several times
+    #[arg(long, value_name = "N")]
+    elf: Vec<usize>,
+
+    #[command(flatten)]
+    args: Args,
 }
 
 fn main() {
-    let input = parse_input(aoc::input::read_from_stdin());
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_example1() {
-        let input = super::parse_input(String::from(
-            "1000
-2000
-3000
+    let cli = Cli::parse();
 
-4000
-
-5000
-6000
-
-7000
-8000
-9000
-
-10000",
-        ));
-
-        assert_eq!(super::part1(&input), "24000");
+    let mut queries = Vec::new();
+    if let Some(k) = cli.top {
+        queries.push(Query::Top { k, ties: cli.ties });
+    }
+    queries.extend(cli.percentile.iter().map(|&p| Query::Percentile(p)));
+    queries.extend(cli.histogram.map(Query::Histogram));
+    queries.extend(cli.elf.iter().map(|&n| Query::Elf(n)));
+
+    if cli.stream {
+        return runner::side_mode::<Day01>(cli.args, |config| {
+            let params = config.params(Day01::YEAR, Day01::DAY);
+            let input = aoc::input::open_input(&config, Day01::YEAR, Day01::DAY)?;
+            let (part1, part2) = day01::stream(
+                input,
+                params.get("top_1", day01::TOP_1),
+                params.get("top_2", day01::TOP_2),
+            )?;
+
+            Ok(format!("Part 1: {}\nPart 2: {}", part1, part2))
+        });
     }
 
-    #[test]
-    fn part2_example1() {
-        let input = super::parse_input(String::from(
-            "1000
-2000
-3000
-
-4000
-
-5000
-6000
-
-7000
-8000
-9000
+    if queries.is_empty() {
+        return runner::main_with::<Day01>(cli.args);
+    }
 
-10000",
-        ));
+    runner::side_mode::<Day01>(cli.args, move |config| {
+        let input = aoc::input::read_input(&config, Day01::YEAR, Day01::DAY)?;
+        let inventory = input.parse::<day01::Inventory>()?;
 
-        assert_eq!(super::part2(&input), "45000");
-    }
+        let reports = queries
+            .into_iter()
+            .map(|query| day01::query(&inventory, query))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(reports.join("\n"))
+    });
 }
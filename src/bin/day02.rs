@@ -1,98 +1,65 @@
-#[derive(Debug)]
-enum Error {
-    ParseError(String),
-}
-
-#[derive(Debug)]
-struct Play {
-    elf: u8,
-    player: u8,
-}
-
-impl std::str::FromStr for Play {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (elf, player) = s
-            .split_once(' ')
-            .ok_or_else(|| Error::ParseError(s.to_owned()))?;
-
-        let elf: char = elf
-            .chars()
-            .next()
-            .ok_or_else(|| Error::ParseError(elf.to_owned()))?;
-        let player: char = player
-            .chars()
-            .next()
-            .ok_or_else(|| Error::ParseError(player.to_owned()))?;
-
-        return Ok(Play {
-            elf: elf as u8 - 'A' as u8 + 1,
-            player: player as u8 - 'X' as u8 + 1,
-        });
-    }
-}
-
-fn parse_input(input: impl AsRef<str>) -> Vec<Play> {
-    aoc::parsing::lines_to_vec::<Play>(input).unwrap()
-}
-
-fn strategy1(play: &Play) -> u32 {
-    if (play.elf == 3 && play.player == 1) || (play.elf + 1 == play.player) {
-        return 6 + play.player as u32;
-    } else if play.elf == play.player {
-        return 3 + play.player as u32;
-    } else {
-        return play.player as u32;
-    }
-}
-
-fn part1(input: &Vec<Play>) -> String {
-    return input.into_iter().map(strategy1).sum::<u32>().to_string();
-}
-
-fn strategy2(play: &Play) -> u32 {
-    if play.player == 1 {
-        return ((play.elf as i32 - 2).rem_euclid(3) + 1) as u32;
-    } else if play.player == 2 {
-        return 3 + play.elf as u32;
-    } else {
-        return 6 + (play.elf as u32 % 3) + 1;
-    }
-}
-
-fn part2(input: &Vec<Play>) -> String {
-    return input.into_iter().map(strategy2).sum::<u32>().to_string();
+use clap::Parser;
+
+use aoc::config::Args;
+use aoc::runner;
+use aoc::solution::Solution;
+use aoc::y2022::day02::{self, Day02, Game};
+
+/// Day 2 of 2022; with `--decrypt`, the score of every reading of the second
+/// column of the strategy guide.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Rank every mapping of the second column to moves or outcomes by the
+    /// score it gives, best first
+    #[arg(long)]
+    decrypt: bool,
+
+    /// With `--decrypt`, also show the mapping whose score is the closest to
+    /// this one
+    #[arg(long, requires = "decrypt")]
+    target: Option<u64>,
+
+    #[command(flatten)]
+    args: Args,
 }
 
 fn main() {
-    let input = parse_input(aoc::input::read_from_stdin());
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_example1() {
-        let input = super::parse_input(
-            "A Y
-B X
-C Z",
-        );
-
-        assert_eq!(super::part1(&input), "15");
+    let cli = Cli::parse();
+    if !cli.decrypt {
+        return runner::main_with::<Day02>(cli.args);
     }
 
-    #[test]
-    fn part2_example1() {
-        let input = super::parse_input(
-            "A Y
-B X
-C Z",
-        );
-
-        assert_eq!(super::part2(&input), "12");
-    }
+    runner::side_mode::<Day02>(cli.args, move |config| {
+        let game = match config
+            .params(Day02::YEAR, Day02::DAY)
+            .get("moves", day02::MOVES)
+        {
+            3 => Game::rock_paper_scissors(),
+            moves => Game::new(moves)?,
+        };
+        let input = aoc::input::read_input(&config, Day02::YEAR, Day02::DAY)?;
+        let plays = aoc::parsing::lines_to_vec(input)?;
+        let ranking = day02::decrypt(&game, &plays)?;
+
+        let mut lines: Vec<String> = ranking
+            .iter()
+            .map(|d| format!("{:>8} {}", d.score, d.mapping.describe(&game)))
+            .collect();
+        if let Some(best) = ranking.first() {
+            lines.push(format!(
+                "Best: {} {}",
+                best.score,
+                best.mapping.describe(&game)
+            ));
+        }
+        if let Some(closest) = cli.target.and_then(|t| day02::closest(&ranking, t)) {
+            lines.push(format!(
+                "Closest: {} {}",
+                closest.score,
+                closest.mapping.describe(&game)
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    });
 }
@@ -1,108 +1,37 @@
-use std::str::FromStr;
+use clap::Parser;
 
-#[derive(Debug)]
-struct Range {
-    low: usize,
-    high: usize,
-}
-
-impl FromStr for Range {
-    type Err = aoc::error::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (low, high) = s
-            .split_once("-")
-            .ok_or_else(|| Self::Err::ParseError(s.to_owned()))?;
-
-        return Ok(Range {
-            low: low
-                .parse()
-                .map_err(|_| Self::Err::ParseError(s.to_owned()))?,
-            high: high
-                .parse()
-                .map_err(|_| Self::Err::ParseError(s.to_owned()))?,
-        });
-    }
-}
-
-#[derive(Debug)]
-struct Pair {
-    r1: Range,
-    r2: Range,
-}
-
-impl FromStr for Pair {
-    type Err = aoc::error::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (r1, r2) = s
-            .split_once(",")
-            .ok_or_else(|| Self::Err::ParseError(s.to_owned()))?;
-
-        return Ok(Pair {
-            r1: r1.parse()?,
-            r2: r2.parse()?,
-        });
-    }
-}
-
-fn parse_input(input: impl AsRef<str>) -> Vec<Pair> {
-    aoc::parsing::lines_to_vec(input).unwrap()
-}
+use aoc::config::Args;
+use aoc::runner;
+use aoc::solution::Solution;
+use aoc::y2022::day04::{self, Day04, Query};
 
-fn part1(pairs: &Vec<Pair>) -> String {
-    pairs
-        .into_iter()
-        .filter(|pair| {
-            (pair.r1.low <= pair.r2.low && pair.r2.high <= pair.r1.high)
-                || (pair.r2.low <= pair.r1.low && pair.r1.high <= pair.r2.high)
-        })
-        .count()
-        .to_string()
-}
+/// Day 4 of 2022; with `--query`, reports on the assignments of every elf of
+/// the input instead of the pairs of a line.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// What to report, in order; may be given several times
+    #[arg(long, value_enum)]
+    query: Vec<Query>,
 
-fn part2(pairs: &Vec<Pair>) -> String {
-    pairs
-        .into_iter()
-        .filter(|pair| pair.r1.low <= pair.r2.high && pair.r2.low <= pair.r1.high)
-        .count()
-        .to_string()
+    #[command(flatten)]
+    args: Args,
 }
 
 fn main() {
-    let input = parse_input(aoc::input::read_from_stdin());
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_example1() {
-        let input = super::parse_input(
-            "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8",
-        );
-
-        assert_eq!(super::part1(&input), "2");
+    let cli = Cli::parse();
+    if cli.query.is_empty() {
+        return runner::main_with::<Day04>(cli.args);
     }
 
-    #[test]
-    fn part2_example1() {
-        let input = super::parse_input(
-            "2-4,6-8
-2-3,4-5
-5-7,7-9
-2-8,3-7
-6-6,4-6
-2-6,4-8",
-        );
-
-        assert_eq!(super::part2(&input), "4");
-    }
+    runner::side_mode::<Day04>(cli.args, move |config| {
+        let input = aoc::input::read_input(&config, Day04::YEAR, Day04::DAY)?;
+        let pairs = aoc::parsing::lines_to_vec::<day04::Pair>(input)?;
+
+        let reports: Vec<String> = cli
+            .query
+            .iter()
+            .map(|&query| day04::query(&pairs, query))
+            .collect();
+        Ok(reports.join("\n"))
+    });
 }
@@ -1,49 +1,64 @@
-use std::collections::HashSet;
+use std::io::Write;
 
-fn parse_input(input: impl AsRef<str>) -> Vec<char> {
-    return input.as_ref().chars().collect::<Vec<char>>();
-}
+use clap::Parser;
 
-fn solve(input: &Vec<char>, window: usize) -> usize {
-    for i in 0..input.len() - window {
-        let s = &input[i..i + window].iter().collect::<HashSet<_>>();
+use aoc::config::{Args, Format};
+use aoc::runner;
+use aoc::solution::Solution;
+use aoc::y2022::day06::{self, Day06};
 
-        if s.len() == window {
-            return i + window;
-        }
-    }
+/// Day 6 of 2022; with `--window`, reads the datastream as it comes instead
+/// of holding it in memory.
+#[derive(Debug, Parser)]
+struct Cli {
+    /// Find the markers of N distinct characters; may be given several times
+    #[arg(long, value_name = "N")]
+    window: Vec<usize>,
 
-    0
-}
+    /// Print every marker as it is found, as `<window> <end>` or as a JSON
+    /// object per line, not only the first of each
+    #[arg(long, requires = "window")]
+    all: bool,
 
-fn part1(input: &Vec<char>) -> String {
-    return solve(input, 4).to_string();
-}
-
-fn part2(input: &Vec<char>) -> String {
-    return solve(input, 14).to_string();
+    #[command(flatten)]
+    args: Args,
 }
 
 fn main() {
-    let input = parse_input(aoc::input::read_from_stdin());
-
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
-
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn part1_example1() {
-        let input = super::parse_input("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
-
-        assert_eq!(super::part1(&input), "7");
+    let cli = Cli::parse();
+    if cli.window.is_empty() {
+        return runner::main_with::<Day06>(cli.args);
     }
 
-    #[test]
-    fn part2_example1() {
-        let input = super::parse_input("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+    runner::side_mode::<Day06>(cli.args, move |config| {
+        let input = aoc::input::open_input(&config, Day06::YEAR, Day06::DAY)?;
+        let mut lines = Vec::new();
 
-        assert_eq!(super::part2(&input), "19");
-    }
+        if cli.all {
+            // There may be a marker for every character, so none are kept.
+            let mut out = std::io::BufWriter::new(std::io::stdout().lock());
+            for marker in day06::markers(input, &cli.window)? {
+                let marker = marker?;
+                match config.format {
+                    Format::Text => writeln!(out, "{} {}", marker.window, marker.end)?,
+                    Format::Json => writeln!(
+                        out,
+                        "{}",
+                        serde_json::json!({ "window": marker.window, "end": marker.end })
+                    )?,
+                }
+            }
+            out.flush()?;
+        } else {
+            let firsts = day06::first_markers(input, &cli.window)?;
+            for (window, first) in cli.window.iter().zip(firsts) {
+                match first {
+                    Some(end) => lines.push(format!("Window {}: {}", window, end)),
+                    None => lines.push(format!("Window {}: not found", window)),
+                }
+            }
+        }
+
+        Ok(lines.join("\n"))
+    });
 }
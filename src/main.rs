@@ -1,3 +1,428 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use aoc::answers::{Answers, Verdict};
+use aoc::cache::Cache;
+use aoc::config::{Args, Config};
+use aoc::registry::{self, Entry};
+use aoc::submit::{History, Outcome};
+
+/// Counts the allocations for `aoc run --memory`.
+#[global_allocator]
+static ALLOCATOR: aoc::memory::Counting = aoc::memory::Counting;
+
+/// Runs the solutions of every event.
+#[derive(Debug, Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve some days, every day of the year by default, and check the
+    /// answers against the answer store
+    Run {
+        /// The days to run
+        days: Vec<u32>,
+
+        /// Store the answers of the solved parts, replacing the stored ones
+        #[arg(long)]
+        save: bool,
+
+        /// Measure the peak and total memory allocated by each stage
+        #[arg(long)]
+        memory: bool,
+
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Download the inputs of some days, every unlocked day of the year by
+    /// default, to the input directory
+    Fetch {
+        /// The days to download
+        days: Vec<u32>,
+
+        /// Download the inputs that are already in the input directory again
+        #[arg(long)]
+        force: bool,
+
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Solve a part of a day and submit its answer to the site
+    Submit {
+        day: u32,
+        part: u32,
+
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Generate the module of a new day from the template and register it
+    New {
+        day: u32,
+
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Browse the days of the year in a terminal dashboard, to run them, test
+    /// them and compare their answers with the answer store
+    #[cfg(feature = "tui")]
+    Dashboard {
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Solve some days, every day of the year by default, with another solver
+    /// as well and report the parts on which they disagree
+    Compare {
+        /// The days to compare
+        days: Vec<u32>,
+
+        /// The command of the other solver; `{year}`, `{day}` and `{key}`
+        /// (`dayNN`) are replaced [default: `reference` in aoc.toml]
+        #[arg(long)]
+        reference: Option<String>,
+
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Write an HTML page of the results of `aoc run --format json`, with
+    /// their timings, example tests and the pictures of the days that draw one
+    Report {
+        /// The JSON reports, one per line [default: stdin]
+        results: Option<PathBuf>,
+
+        /// The output of `cargo test`, for the results of the example tests
+        #[arg(long)]
+        tests: Option<PathBuf>,
+
+        /// Where to write the page
+        #[arg(short, long, default_value = "report.html")]
+        output: PathBuf,
+
+        #[command(flatten)]
+        args: Args,
+    },
+    /// List the registered days
+    List,
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    std::process::exit(2);
+}
+
+/// The configuration of `args`, with the debug channel set up.
+fn load(args: &Args) -> Config {
+    let config = Config::load(args).unwrap_or_else(|e| fail(e));
+    config.init_log().unwrap_or_else(|e| fail(e));
+
+    return config;
+}
+
+/// The registered `days` of the configured year, every one when empty.
+fn select(config: &Config, days: &[u32]) -> Vec<&'static Entry> {
+    let entries: Vec<&Entry> = match days.is_empty() {
+        true => registry::year(config.year).collect(),
+        false => days
+            .iter()
+            .map(|&day| {
+                registry::find(config.year, day).unwrap_or_else(|| {
+                    fail(format!(
+                        "there is no solution for day {} of {}",
+                        day, config.year
+                    ))
+                })
+            })
+            .collect(),
+    };
+
+    if entries.len() != 1 && config.input.is_some() {
+        fail("--input can only be used when running a single day");
+    }
+
+    return entries;
+}
+
+/// The input of `entry`, one of `entries`: only a single day can read it from
+/// `--input` or stdin.
+fn read(config: &Config, entries: &[&Entry], entry: &Entry) -> Result<String, aoc::error::Error> {
+    return match entries.len() {
+        1 => aoc::input::read_input(config, entry.year, entry.day),
+        _ => aoc::input::read_file(config.input_path(entry.year, entry.day)),
+    };
+}
+
+fn run(days: Vec<u32>, save: bool, memory: bool, args: Args) {
+    if memory {
+        aoc::memory::enable();
+    }
+
+    let config = load(&args);
+    let mut answers = Answers::load(&config.answers).unwrap_or_else(|e| fail(e));
+    let mut cache = Cache::open(&config).unwrap_or_else(|e| fail(e));
+
+    let entries = select(&config, &days);
+
+    let mut ok = true;
+    for entry in &entries {
+        let input = match read(&config, &entries, entry) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}/{}: {}", entry.year, aoc::config::day_key(entry.day), e);
+                ok = false;
+                continue;
+            }
+        };
+
+        let report = cache.run(entry, input, &config);
+
+        if entries.len() != 1 && config.format == aoc::config::Format::Text {
+            match report.cached {
+                true => println!("{} (cached)", report.name()),
+                false => println!("{}", report.name()),
+            }
+        }
+        aoc::runner::print(&report, config.format);
+
+        for (verdict, part) in answers.verify(&report).into_iter().zip(1..) {
+            if let Verdict::Wrong { expected } = verdict {
+                eprintln!("{}: part {} should be {}", report.name(), part, expected);
+                ok = false;
+            }
+        }
+
+        ok &= report.is_solved();
+        if save {
+            answers.record(&report);
+        }
+    }
+
+    if save {
+        answers.save(&config.answers).unwrap_or_else(|e| fail(e));
+    }
+    if let Err(e) = cache.save() {
+        aoc::warn!("{}: {}", config.cache.display(), e);
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn fetch(days: Vec<u32>, force: bool, args: Args) {
+    let config = load(&args);
+    let client = config.client().unwrap_or_else(|e| fail(e));
+
+    let days = match days.is_empty() {
+        true => (1..=25)
+            .filter(|&day| aoc::client::is_unlocked(config.year, day))
+            .collect(),
+        false => days,
+    };
+
+    let mut ok = true;
+    for day in days {
+        let name = format!("{}/{}", config.year, aoc::config::day_key(day));
+        if !aoc::client::is_unlocked(config.year, day) {
+            eprintln!("{}: not unlocked yet", name);
+            ok = false;
+            continue;
+        }
+
+        match aoc::input::fetch_input(&client, &config, config.year, day, force) {
+            Ok(true) => println!("{}: downloaded", name),
+            Ok(false) => println!("{}: cached", name),
+            Err(e) => {
+                eprintln!("{}: {}", name, e);
+                ok = false;
+            }
+        }
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn submit(day: u32, part: u32, args: Args) {
+    let config = load(&args);
+    let entry = registry::find(config.year, day).unwrap_or_else(|| {
+        fail(format!(
+            "there is no solution for day {} of {}",
+            day, config.year
+        ))
+    });
+    if part == 0 || part > entry.parts {
+        fail(format!("day {} has no part {}", day, part));
+    }
+
+    let input = aoc::input::read_input(&config, entry.year, entry.day).unwrap_or_else(|e| fail(e));
+    let report = entry.run(input, config.params(entry.year, entry.day), config.timeout);
+    let answer = match &report.parts[part as usize - 1].answer {
+        Some(answer) => answer.clone(),
+        None => {
+            aoc::runner::print(&report, config.format);
+            std::process::exit(1);
+        }
+    };
+
+    let client = config.client().unwrap_or_else(|e| fail(e));
+    let mut history = History::load(&config.history).unwrap_or_else(|e| fail(e));
+
+    let outcome = history.submit(&client, entry.year, entry.day, part, &answer);
+    history.save(&config.history).unwrap_or_else(|e| fail(e));
+
+    let name = report.name();
+    match outcome {
+        Ok(Outcome::Correct) => {
+            println!("{}: part {} is {}", name, part, answer);
+
+            let mut answers = Answers::load(&config.answers).unwrap_or_else(|e| fail(e));
+            answers.set(entry.year, entry.day, part, answer);
+            answers.save(&config.answers).unwrap_or_else(|e| fail(e));
+        }
+        Ok(outcome) => {
+            println!("{}: part {} is not {}: {}", name, part, answer, outcome);
+            std::process::exit(1);
+        }
+        Err(e) => fail(format!("{}: {}", name, e)),
+    }
+}
+
+fn new(day: u32, args: Args) {
+    let config = load(&args);
+    if registry::find(config.year, day).is_some() {
+        fail(format!("day {} of {} already exists", day, config.year));
+    }
+
+    let examples = config.example_path(config.year, day);
+    let root = std::env::current_dir().unwrap_or_else(|e| fail(e));
+    let changed =
+        aoc::scaffold::new_day(&root, &examples, config.year, day).unwrap_or_else(|e| fail(e));
+
+    for file in changed {
+        println!("{}", file.strip_prefix(&root).unwrap_or(&file).display());
+    }
+}
+
+fn compare(days: Vec<u32>, reference: Option<String>, args: Args) {
+    let config = load(&args);
+    let reference = reference.or(config.reference.clone()).unwrap_or_else(|| {
+        fail("no reference solver, set `reference` in aoc.toml or pass --reference")
+    });
+    let mut cache = Cache::open(&config).unwrap_or_else(|e| fail(e));
+
+    let entries = select(&config, &days);
+
+    let mut ok = true;
+    for entry in &entries {
+        let name = format!("{}/{}", entry.year, aoc::config::day_key(entry.day));
+        let input = match read(&config, &entries, entry) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}: {}", name, e);
+                ok = false;
+                continue;
+            }
+        };
+
+        let command = aoc::reference::command(&reference, entry.year, entry.day);
+        let theirs = match aoc::reference::run(&command, &input, config.timeout) {
+            Ok(theirs) => theirs,
+            Err(e) => {
+                eprintln!("{}: {}", name, e);
+                ok = false;
+                continue;
+            }
+        };
+        let report = cache.run(entry, input, &config);
+
+        let disagreements = aoc::reference::compare(&report, &theirs);
+        if disagreements.is_empty() {
+            println!("{}: agrees", name);
+            continue;
+        }
+
+        ok = false;
+        let show = |answer: &Option<String>| match answer {
+            Some(answer) if answer.contains('\n') => format!("\n{}\n", answer),
+            Some(answer) => answer.clone(),
+            None => "no answer".to_string(),
+        };
+        for d in disagreements {
+            println!(
+                "{}: part {} disagrees: ours {}, reference {}",
+                name,
+                d.part,
+                show(&d.ours),
+                show(&d.theirs)
+            );
+        }
+    }
+
+    if let Err(e) = cache.save() {
+        aoc::warn!("{}: {}", config.cache.display(), e);
+    }
+
+    if !ok {
+        std::process::exit(1);
+    }
+}
+
+fn report(results: Option<PathBuf>, tests: Option<PathBuf>, output: PathBuf, args: Args) {
+    let config = load(&args);
+    let answers = Answers::load(&config.answers).unwrap_or_else(|e| fail(e));
+
+    let results = match results {
+        Some(path) => aoc::input::read_file(path).unwrap_or_else(|e| fail(e)),
+        None => aoc::input::read_from_stdin(),
+    };
+    let reports = aoc::report::load_results(&results).unwrap_or_else(|e| fail(e));
+    let tests = match tests {
+        Some(path) => {
+            aoc::report::parse_tests(&aoc::input::read_file(path).unwrap_or_else(|e| fail(e)))
+        }
+        None => Default::default(),
+    };
+
+    let title = aoc::report::title(&reports);
+    let days = aoc::report::days(reports, &answers, &tests);
+    std::fs::write(&output, aoc::report::html(&title, &days)).unwrap_or_else(|e| fail(e));
+
+    println!("{}", output.display());
+}
+
 fn main() {
-    println!("Hello, world!");
+    match Cli::parse().command {
+        Command::Run {
+            days,
+            save,
+            memory,
+            args,
+        } => run(days, save, memory, args),
+        Command::Fetch { days, force, args } => fetch(days, force, args),
+        Command::Submit { day, part, args } => submit(day, part, args),
+        Command::New { day, args } => new(day, args),
+        #[cfg(feature = "tui")]
+        Command::Dashboard { args } => aoc::dashboard::main(args).unwrap_or_else(|e| fail(e)),
+        Command::Compare {
+            days,
+            reference,
+            args,
+        } => compare(days, reference, args),
+        Command::Report {
+            results,
+            tests,
+            output,
+            args,
+        } => report(results, tests, output, args),
+        Command::List => {
+            for entry in registry::entries() {
+                println!("{} day {:2}", entry.year, entry.day);
+            }
+        }
+    }
 }
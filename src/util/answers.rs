@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::day_key;
+use crate::error::Error;
+use crate::runner::{Report, Status};
+
+/// The accepted answers, stored as
+///
+/// ```toml
+/// [2022.day01]
+/// part1 = "69289"
+/// part2 = "205615"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>);
+
+/// How an answer of a [`Report`] compares to the stored one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// There is no stored answer for this part yet.
+    Unknown,
+    Correct,
+    Wrong {
+        expected: String,
+    },
+}
+
+fn part_key(part: u32) -> String {
+    format!("part{}", part)
+}
+
+impl FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return toml::from_str(s).map_err(|e| Error::ParseError(e.to_string()));
+    }
+}
+
+impl Answers {
+    /// Loads the answers from `path`; a missing file holds no answers.
+    pub fn load(path: impl AsRef<Path>) -> Result<Answers, Error> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        return std::fs::read_to_string(path)?.parse::<Answers>();
+    }
+
+    pub fn save(self: &Self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let text = toml::to_string(self).map_err(|e| Error::IoError(e.to_string()))?;
+        std::fs::write(path, text)?;
+
+        return Ok(());
+    }
+
+    pub fn get(self: &Self, year: u32, day: u32, part: u32) -> Option<&str> {
+        return self
+            .0
+            .get(&year.to_string())?
+            .get(&day_key(day))?
+            .get(&part_key(part))
+            .map(|answer| answer.as_str());
+    }
+
+    pub fn set(self: &mut Self, year: u32, day: u32, part: u32, answer: impl Into<String>) {
+        self.0
+            .entry(year.to_string())
+            .or_default()
+            .entry(day_key(day))
+            .or_default()
+            .insert(part_key(part), answer.into());
+    }
+
+    /// Compares every solved part of `report` with the stored answer.
+    pub fn verify(self: &Self, report: &Report) -> Vec<Verdict> {
+        return report
+            .parts
+            .iter()
+            .zip(1..)
+            .map(
+                |(stage, part)| match (self.get(report.year, report.day, part), &stage.answer) {
+                    (Some(expected), Some(answer)) if expected != answer => Verdict::Wrong {
+                        expected: expected.to_string(),
+                    },
+                    (Some(_), Some(_)) => Verdict::Correct,
+                    _ => Verdict::Unknown,
+                },
+            )
+            .collect();
+    }
+
+    /// Stores the answers of every solved part of `report`.
+    pub fn record(self: &mut Self, report: &Report) {
+        for (stage, part) in report.parts.iter().zip(1..) {
+            if let (Status::Solved, Some(answer)) = (stage.status, &stage.answer) {
+                self.set(report.year, report.day, part, answer.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answers, Verdict};
+    use crate::runner::{Report, Stage, Status};
+    use std::time::Duration;
+
+    fn report(answers: &[&str]) -> Report {
+        let stage = |answer: Option<&str>| Stage {
+            status: Status::Solved,
+            answer: answer.map(|a| a.to_string()),
+            time: Duration::ZERO,
+            memory: None,
+        };
+
+        Report {
+            year: 2022,
+            day: 1,
+            parse: stage(None),
+            parts: answers.iter().map(|a| stage(Some(a))).collect(),
+            render: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = Answers::default();
+        answers.set(2022, 1, 1, "24000");
+        answers.set(2021, 25, 1, "7");
+
+        let text = toml::to_string(&answers).unwrap();
+        assert!(text.contains("[2022.day01]"));
+
+        let parsed = text.parse::<Answers>().unwrap();
+        assert_eq!(parsed, answers);
+        assert_eq!(parsed.get(2022, 1, 1), Some("24000"));
+        assert_eq!(parsed.get(2022, 1, 2), None);
+    }
+
+    #[test]
+    fn verify_compares_each_part() {
+        let answers = "[2022.day01]\npart1 = \"24000\"\npart2 = \"45000\""
+            .parse::<Answers>()
+            .unwrap();
+
+        assert_eq!(
+            answers.verify(&report(&["24000", "1"])),
+            vec![
+                Verdict::Correct,
+                Verdict::Wrong {
+                    expected: "45000".to_string()
+                }
+            ]
+        );
+
+        let mut empty = Answers::default();
+        assert_eq!(empty.verify(&report(&["24000"])), vec![Verdict::Unknown]);
+
+        empty.record(&report(&["24000"]));
+        assert_eq!(empty.verify(&report(&["24000"])), vec![Verdict::Correct]);
+    }
+}
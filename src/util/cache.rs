@@ -0,0 +1,358 @@
+//! Answers and timings of earlier runs, so that days whose input, parameters
+//! and code did not change are not solved again.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::{day_key, Config, Params};
+use crate::error::Error;
+use crate::registry::Entry;
+use crate::runner::{Report, Stage, Status};
+
+/// A stage of an earlier run, with what it was computed from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Cached {
+    input: String,
+    build: String,
+    #[serde(flatten)]
+    stage: Stage,
+}
+
+/// The solved stages of the last run of every day, stored as JSON and keyed by
+/// `YEAR/dayNN/partN` (`parse` for the parsing, and `render` for the picture
+/// of [`Report::render`], kept as its answer). A stage is only reused when
+/// both the input and the code are the ones it was computed with.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    path: PathBuf,
+    build: String,
+    stages: BTreeMap<String, Cached>,
+}
+
+/// FNV-1a, which unlike the hasher of the standard library is the same for
+/// every build.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    return bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+/// Identifies the build by the version of the crate, the hash of its sources,
+/// its profile and its features, taken by the build script, so that any
+/// change to the code or to how it is built invalidates the cache while `aoc`
+/// and the `dayNN` binaries share it.
+pub fn build_id() -> &'static str {
+    return concat!(
+        env!("CARGO_PKG_VERSION"),
+        "-",
+        env!("AOC_SOURCE_HASH"),
+        "-",
+        env!("AOC_BUILD")
+    );
+}
+
+/// The hash of an input together with the parameters it is solved with.
+pub fn input_key(input: &str, params: &Params) -> String {
+    let mut hash = fnv1a(FNV_OFFSET, input.as_bytes());
+    for (name, value) in params.entries() {
+        hash = fnv1a(hash, &[0]);
+        hash = fnv1a(hash, name.as_bytes());
+        hash = fnv1a(hash, &[0]);
+        hash = fnv1a(hash, value.as_bytes());
+    }
+
+    return format!("{:016x}", hash);
+}
+
+fn stage_key(year: u32, day: u32, part: u32) -> String {
+    return match part {
+        0 => format!("{}/{}/parse", year, day_key(day)),
+        _ => format!("{}/{}/part{}", year, day_key(day), part),
+    };
+}
+
+fn render_key(year: u32, day: u32) -> String {
+    return format!("{}/{}/render", year, day_key(day));
+}
+
+impl Cache {
+    /// Loads the cache at `path` for the running binary. A missing or
+    /// unreadable file is an empty cache.
+    pub fn load(path: impl AsRef<Path>) -> Cache {
+        return Cache::with_build(path, build_id());
+    }
+
+    fn with_build(path: impl AsRef<Path>, build: &str) -> Cache {
+        let path = path.as_ref();
+        let stages = std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default();
+
+        return Cache {
+            path: path.to_path_buf(),
+            build: build.to_string(),
+            stages,
+        };
+    }
+
+    /// The cache configured by `config`, emptied first when it says so.
+    pub fn open(config: &Config) -> Result<Cache, Error> {
+        if config.clear_cache {
+            Cache::clear(&config.cache)?;
+        }
+
+        return Ok(Cache::load(&config.cache));
+    }
+
+    pub fn save(self: &Self) -> Result<(), Error> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let text = serde_json::to_string_pretty(&self.stages)
+            .map_err(|e| Error::IoError(e.to_string()))?;
+        std::fs::write(&self.path, text)?;
+
+        return Ok(());
+    }
+
+    /// Deletes the cache at `path`, if there is one.
+    pub fn clear(path: impl AsRef<Path>) -> Result<(), Error> {
+        return match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        };
+    }
+
+    /// The report of an earlier run of `day` of `year` on the input of
+    /// `input_key`, when every one of its `parts` was solved by this binary,
+    /// and its picture was drawn when it `has_render`.
+    pub fn get(
+        self: &Self,
+        year: u32,
+        day: u32,
+        parts: u32,
+        has_render: bool,
+        input_key: &str,
+    ) -> Option<Report> {
+        if self.build.is_empty() {
+            return None;
+        }
+
+        let render = match has_render {
+            true => {
+                let cached = self.stages.get(&render_key(year, day))?;
+                if cached.input != input_key || cached.build != self.build {
+                    return None;
+                }
+                cached.stage.answer.clone()
+            }
+            false => None,
+        };
+
+        let mut stages = (0..=parts)
+            .map(|part| {
+                let cached = self.stages.get(&stage_key(year, day, part))?;
+                if cached.input != input_key || cached.build != self.build {
+                    return None;
+                }
+                // A profiled run needs the memory the stage used, and other
+                // runs do not report it.
+                let mut stage = cached.stage.clone();
+                if !crate::memory::is_enabled() {
+                    stage.memory = None;
+                } else if stage.memory.is_none() {
+                    return None;
+                }
+
+                return Some(stage);
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let parse = stages.remove(0);
+
+        return Some(Report {
+            year,
+            day,
+            parse,
+            parts: stages,
+            render,
+            cached: true,
+        });
+    }
+
+    /// Runs `entry` on `input`, or takes its report from the cache unless
+    /// `config` says not to, see [`run`].
+    pub fn run(self: &mut Self, entry: &Entry, input: String, config: &Config) -> Report {
+        let params = config.params(entry.year, entry.day);
+        let key = input_key(&input, &params);
+
+        if !config.no_cache {
+            if let Some(report) =
+                self.get(entry.year, entry.day, entry.parts, entry.has_render, &key)
+            {
+                return report;
+            }
+        }
+
+        let report = entry.run(input, params, config.timeout);
+        self.insert(&report, &key);
+
+        return report;
+    }
+
+    /// Stores the solved stages of `report`, run on the input of `input_key`,
+    /// and its picture. A day that could not draw one is solved again, to try
+    /// again.
+    pub fn insert(self: &mut Self, report: &Report, input_key: &str) {
+        if self.build.is_empty() || report.cached {
+            return;
+        }
+
+        let key = render_key(report.year, report.day);
+        match &report.render {
+            Some(render) => {
+                let stage = Stage {
+                    status: Status::Solved,
+                    answer: Some(render.clone()),
+                    time: Default::default(),
+                    memory: None,
+                };
+                let cached = Cached {
+                    input: input_key.to_string(),
+                    build: self.build.clone(),
+                    stage,
+                };
+                self.stages.insert(key, cached);
+            }
+            None => {
+                self.stages.remove(&key);
+            }
+        }
+
+        let stages = std::iter::once(&report.parse).chain(&report.parts);
+        for (part, stage) in (0..).zip(stages) {
+            let key = stage_key(report.year, report.day, part);
+            if stage.status != Status::Solved {
+                self.stages.remove(&key);
+                continue;
+            }
+
+            let cached = Cached {
+                input: input_key.to_string(),
+                build: self.build.clone(),
+                stage: stage.clone(),
+            };
+            self.stages.insert(key, cached);
+        }
+    }
+}
+
+/// Runs `entry` on `input` with the settings of `config`, reusing the answers
+/// of an earlier run when possible and storing the new ones otherwise. A cache
+/// that cannot be cleared or saved only costs time, so that is merely a warning.
+pub fn run(entry: &Entry, input: String, config: &Config) -> Report {
+    let mut cache = Cache::open(config).unwrap_or_else(|e| {
+        crate::warn!("{}: {}", config.cache.display(), e);
+        Cache::load(&config.cache)
+    });
+    let report = cache.run(entry, input, config);
+
+    if let Err(e) = cache.save() {
+        crate::warn!("{}: {}", config.cache.display(), e);
+    }
+
+    return report;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Cache;
+    use crate::config::Params;
+    use crate::runner::{Report, Stage, Status};
+    use std::time::Duration;
+
+    fn stage(status: Status, answer: Option<&str>) -> Stage {
+        Stage {
+            status,
+            answer: answer.map(String::from),
+            time: Duration::from_millis(3),
+            // Profiling may be enabled by the tests of `crate::memory`.
+            memory: Some(Default::default()),
+        }
+    }
+
+    #[test]
+    fn input_key_covers_the_params() {
+        let mut params = Params::default();
+        let plain = super::input_key("1\n", &params);
+
+        params.set("minutes", "30");
+        assert_ne!(super::input_key("1\n", &params), plain);
+        assert_ne!(super::input_key("2\n", &Params::default()), plain);
+        assert_eq!(super::input_key("1\n", &Params::default()), plain);
+    }
+
+    #[test]
+    fn get_needs_the_same_input_and_build() {
+        let path = std::env::temp_dir().join(format!("aoc-cache-{}.json", std::process::id()));
+        let report = Report {
+            year: 2022,
+            day: 16,
+            parse: stage(Status::Solved, None),
+            parts: vec![
+                stage(Status::Solved, Some("1651")),
+                stage(Status::TimedOut, None),
+            ],
+            render: None,
+            cached: false,
+        };
+
+        let mut cache = Cache::with_build(&path, "a");
+        cache.insert(&report, "input");
+        assert!(cache.get(2022, 16, 1, false, "input").is_some());
+        assert!(cache.get(2022, 16, 2, false, "input").is_none());
+        cache.save().unwrap();
+
+        let cached = Cache::with_build(&path, "a")
+            .get(2022, 16, 1, false, "input")
+            .unwrap();
+        assert!(cached.cached);
+        assert_eq!(cached.parts.len(), 1);
+        assert_eq!(cached.parts[0].answer.as_deref(), Some("1651"));
+        assert_eq!(cached.parts[0].time, report.parts[0].time);
+
+        assert!(Cache::with_build(&path, "a")
+            .get(2022, 16, 1, false, "other input")
+            .is_none());
+        assert!(Cache::with_build(&path, "b")
+            .get(2022, 16, 1, false, "input")
+            .is_none());
+
+        // A day with a picture needs it as well.
+        assert!(cache.get(2022, 16, 1, true, "input").is_none());
+        let drawn = Report {
+            render: Some("#.#".to_string()),
+            ..report.clone()
+        };
+        cache.insert(&drawn, "input");
+        let cached = cache.get(2022, 16, 1, true, "input").unwrap();
+        assert_eq!(cached.render.as_deref(), Some("#.#"));
+        assert!(cache
+            .get(2022, 16, 1, false, "input")
+            .unwrap()
+            .render
+            .is_none());
+
+        Cache::clear(&path).unwrap();
+        Cache::clear(&path).unwrap();
+        assert!(Cache::with_build(&path, "a")
+            .get(2022, 16, 1, false, "input")
+            .is_none());
+    }
+}
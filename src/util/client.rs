@@ -0,0 +1,288 @@
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::error::Error;
+
+/// The site every request goes to unless `base_url` says otherwise.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The environment variable holding the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+const USER_AGENT: &str = concat!("aoc/", env!("CARGO_PKG_VERSION"));
+
+/// A logged in client of the Advent of Code site, or of anything that
+/// answers the same requests, such as a local mock server.
+///
+/// Requests are spaced by at least `interval`, so that fetching a whole
+/// event does not hammer the server.
+#[derive(Debug)]
+pub struct Client {
+    base_url: String,
+    cookie: String,
+    interval: Duration,
+    last: Mutex<Option<Instant>>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// `session` is the value of the `session` cookie, optionally with the
+    /// `session=` prefix.
+    pub fn new(base_url: impl Into<String>, session: &str, interval: Duration) -> Client {
+        let session = session.trim();
+        let cookie = match session.starts_with("session=") {
+            true => session.to_string(),
+            false => format!("session={}", session),
+        };
+
+        return Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            cookie,
+            interval,
+            last: Mutex::new(None),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .redirects(0)
+                .build(),
+        };
+    }
+
+    fn wait(self: &Self) {
+        let mut last = self.last.lock().unwrap();
+        if let Some(last) = *last {
+            let elapsed = last.elapsed();
+            if elapsed < self.interval {
+                thread::sleep(self.interval - elapsed);
+            }
+        }
+        *last = Some(Instant::now());
+    }
+
+    fn url(self: &Self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn body(result: Result<ureq::Response, ureq::Error>, url: &str) -> Result<String, Error> {
+        let response = match result {
+            Ok(response) if response.status() == 200 => response,
+            Ok(response) => {
+                return Err(Error::HttpError(format!(
+                    "{} answered {} {}",
+                    url,
+                    response.status(),
+                    response.status_text()
+                )))
+            }
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                return Err(Error::HttpError(format!(
+                    "{} answered {}: {}",
+                    url,
+                    status,
+                    body.trim()
+                )));
+            }
+            Err(e) => return Err(Error::HttpError(format!("{}: {}", url, e))),
+        };
+
+        return response.into_string().map_err(Error::from);
+    }
+
+    /// GETs `path`, relative to the base URL.
+    pub fn get(self: &Self, path: &str) -> Result<String, Error> {
+        self.wait();
+        let url = self.url(path);
+
+        return Client::body(
+            self.agent.get(&url).set("Cookie", &self.cookie).call(),
+            &url,
+        );
+    }
+
+    /// POSTs `form` to `path`, relative to the base URL.
+    pub fn post_form(self: &Self, path: &str, form: &[(&str, &str)]) -> Result<String, Error> {
+        self.wait();
+        let url = self.url(path);
+
+        return Client::body(
+            self.agent
+                .post(&url)
+                .set("Cookie", &self.cookie)
+                .send_form(form),
+            &url,
+        );
+    }
+
+    /// Downloads the puzzle input of `day` of `year`.
+    pub fn fetch_input(self: &Self, year: u32, day: u32) -> Result<String, Error> {
+        let input = self.get(&format!("/{}/day/{}/input", year, day))?;
+        validate_input(&input)?;
+
+        return Ok(input);
+    }
+}
+
+/// Rejects what the site sends instead of an input: the HTML of the login
+/// page when the session is not valid, or an empty body.
+pub fn validate_input(input: &str) -> Result<(), Error> {
+    let start = input.trim_start().to_ascii_lowercase();
+
+    if start.is_empty() {
+        return Err(Error::HttpError("the input is empty".to_string()));
+    }
+    if start.starts_with("<!doctype html") || start.starts_with("<html") {
+        return Err(Error::HttpError(
+            "got an HTML page instead of the input, is the session cookie valid?".to_string(),
+        ));
+    }
+    if start.starts_with("puzzle inputs differ by user") {
+        return Err(Error::HttpError(
+            "the site asks to log in, is the session cookie valid?".to_string(),
+        ));
+    }
+
+    return Ok(());
+}
+
+/// The days since 1970-01-01 of `year`-12-`day`.
+fn days_from_epoch(year: u32, day: u32) -> u64 {
+    // Days before March 1st of the year, counting from 0000-03-01.
+    let y = year as u64;
+    let days = y * 365 + y / 4 - y / 100 + y / 400;
+    // December is the 10th month of a year starting in March.
+    let days = days + (153 * 9 + 2) / 5 + day as u64 - 1;
+
+    return days - 719468;
+}
+
+/// When `day` of `year` unlocks: at midnight EST, which is UTC-5.
+pub fn unlock_time(year: u32, day: u32) -> SystemTime {
+    let secs = days_from_epoch(year, day) * 24 * 60 * 60 + 5 * 60 * 60;
+    return UNIX_EPOCH + Duration::from_secs(secs);
+}
+
+/// Whether `day` of `year` is already unlocked.
+pub fn is_unlocked(year: u32, day: u32) -> bool {
+    SystemTime::now() >= unlock_time(year, day)
+}
+
+/// A tiny HTTP server for the tests, answering each connection with the next
+/// of a list of canned responses.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Starts the server; the handle returns the request line, headers and
+    /// body of every request once all the responses were sent.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = Vec::new();
+
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+
+            return requests;
+        });
+
+        return (url, handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{stub, Client};
+    use std::time::{Duration, Instant, UNIX_EPOCH};
+
+    #[test]
+    fn fetch_input_sends_the_session() {
+        let (url, server) = stub::serve(vec![(200, "1\n2\n")]);
+        let client = Client::new(url, "abc", Duration::ZERO);
+
+        assert_eq!(client.fetch_input(2022, 1).unwrap(), "1\n2\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2022/day/1/input "));
+        assert!(requests[0].contains("session=abc"));
+    }
+
+    #[test]
+    fn fetch_input_detects_the_login_page() {
+        let (url, server) = stub::serve(vec![
+            (200, "<!DOCTYPE html>\n<html><body>Log in</body></html>"),
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+        ]);
+        let client = Client::new(url, "session=expired", Duration::ZERO);
+
+        assert!(client.fetch_input(2022, 1).is_err());
+        assert!(client.fetch_input(2022, 1).is_err());
+        assert!(client.fetch_input(2022, 26).is_err());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn requests_are_rate_limited() {
+        let (url, server) = stub::serve(vec![(200, "1"), (200, "2")]);
+        let client = Client::new(url, "abc", Duration::from_millis(200));
+
+        let start = Instant::now();
+        client.get("/").unwrap();
+        client.get("/").unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn unlock_time_is_midnight_est() {
+        // 2022-12-01T05:00:00Z
+        let unlock = super::unlock_time(2022, 1)
+            .duration_since(UNIX_EPOCH)
+            .unwrap();
+        assert_eq!(unlock.as_secs(), 1669870800);
+    }
+}
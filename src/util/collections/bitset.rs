@@ -0,0 +1,359 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// A set of integers below 64, stored in the bits of a `u64`.
+///
+/// It is `Copy` and its [`BitSet64::bits`] make a dense index, so it can key
+/// the tables of a dynamic programming solution.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BitSet64(u64);
+
+impl BitSet64 {
+    pub const CAPACITY: usize = 64;
+
+    pub const fn new() -> BitSet64 {
+        return BitSet64(0);
+    }
+
+    /// The set of every integer below `n`.
+    pub const fn full(n: usize) -> BitSet64 {
+        assert!(n <= BitSet64::CAPACITY);
+
+        return match n {
+            64 => BitSet64(u64::MAX),
+            _ => BitSet64((1 << n) - 1),
+        };
+    }
+
+    pub const fn from_bits(bits: u64) -> BitSet64 {
+        return BitSet64(bits);
+    }
+
+    pub const fn bits(self: &Self) -> u64 {
+        return self.0;
+    }
+
+    pub const fn contains(self: &Self, i: usize) -> bool {
+        return i < BitSet64::CAPACITY && self.0 & (1 << i) != 0;
+    }
+
+    /// Adds `i`, returning whether it was missing. Panics when `i` is 64 or
+    /// more.
+    pub fn insert(self: &mut Self, i: usize) -> bool {
+        assert!(i < BitSet64::CAPACITY, "{} does not fit in a BitSet64", i);
+
+        let missing = !self.contains(i);
+        self.0 |= 1 << i;
+
+        return missing;
+    }
+
+    /// Removes `i`, returning whether it was present.
+    pub fn remove(self: &mut Self, i: usize) -> bool {
+        let present = self.contains(i);
+        if present {
+            self.0 &= !(1 << i);
+        }
+
+        return present;
+    }
+
+    /// A copy of the set with `i` added.
+    pub fn with(self: Self, i: usize) -> BitSet64 {
+        let mut set = self;
+        set.insert(i);
+
+        return set;
+    }
+
+    pub const fn len(self: &Self) -> usize {
+        return self.0.count_ones() as usize;
+    }
+
+    pub const fn is_empty(self: &Self) -> bool {
+        return self.0 == 0;
+    }
+
+    pub const fn union(self: Self, other: BitSet64) -> BitSet64 {
+        return BitSet64(self.0 | other.0);
+    }
+
+    pub const fn intersection(self: Self, other: BitSet64) -> BitSet64 {
+        return BitSet64(self.0 & other.0);
+    }
+
+    pub const fn difference(self: Self, other: BitSet64) -> BitSet64 {
+        return BitSet64(self.0 & !other.0);
+    }
+
+    pub const fn is_disjoint(self: &Self, other: &BitSet64) -> bool {
+        return self.0 & other.0 == 0;
+    }
+
+    pub const fn is_subset(self: &Self, other: &BitSet64) -> bool {
+        return self.0 & !other.0 == 0;
+    }
+
+    /// The members, in increasing order.
+    pub fn iter(self: &Self) -> Bits {
+        return Bits(self.0);
+    }
+}
+
+impl fmt::Debug for BitSet64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet64 {
+    fn from_iter<I: IntoIterator<Item = usize>>(items: I) -> Self {
+        let mut set = BitSet64::new();
+        for i in items {
+            set.insert(i);
+        }
+
+        return set;
+    }
+}
+
+impl IntoIterator for BitSet64 {
+    type Item = usize;
+    type IntoIter = Bits;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.iter();
+    }
+}
+
+/// The members of a [`BitSet64`] or of one word of a [`BitSet`].
+#[derive(Debug, Clone)]
+pub struct Bits(u64);
+
+impl Iterator for Bits {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        let i = self.0.trailing_zeros() as usize;
+        self.0 &= self.0 - 1;
+
+        return Some(i);
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        return (len, Some(len));
+    }
+}
+
+/// A set of integers of any size, stored as a growable vector of bits.
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.trimmed().hash(state);
+    }
+}
+
+impl BitSet {
+    /// The words without the trailing empty ones, which the capacity adds.
+    fn trimmed(self: &Self) -> &[u64] {
+        let len = self
+            .words
+            .iter()
+            .rposition(|&word| word != 0)
+            .map_or(0, |i| i + 1);
+        return &self.words[..len];
+    }
+
+    pub fn new() -> BitSet {
+        return BitSet::default();
+    }
+
+    /// An empty set that holds integers below `n` without growing.
+    pub fn with_capacity(n: usize) -> BitSet {
+        return BitSet {
+            words: vec![0; n.div_ceil(64)],
+        };
+    }
+
+    pub fn contains(self: &Self, i: usize) -> bool {
+        return self
+            .words
+            .get(i / 64)
+            .is_some_and(|word| word & (1 << (i % 64)) != 0);
+    }
+
+    /// Adds `i`, growing the set as needed, and returns whether it was missing.
+    pub fn insert(self: &mut Self, i: usize) -> bool {
+        if i / 64 >= self.words.len() {
+            self.words.resize(i / 64 + 1, 0);
+        }
+
+        let missing = !self.contains(i);
+        self.words[i / 64] |= 1 << (i % 64);
+
+        return missing;
+    }
+
+    /// Removes `i`, returning whether it was present.
+    pub fn remove(self: &mut Self, i: usize) -> bool {
+        let present = self.contains(i);
+        if present {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+
+        return present;
+    }
+
+    pub fn clear(self: &mut Self) {
+        self.words.iter_mut().for_each(|word| *word = 0);
+    }
+
+    pub fn len(self: &Self) -> usize {
+        return self
+            .words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        return self.words.iter().all(|&word| word == 0);
+    }
+
+    /// Adds the members of `other`.
+    pub fn union_with(self: &mut Self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Keeps only the members that are also in `other`.
+    pub fn intersect_with(self: &mut Self, other: &BitSet) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    /// Removes the members of `other`.
+    pub fn difference_with(self: &mut Self, other: &BitSet) {
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word &= !other;
+        }
+    }
+
+    pub fn is_disjoint(self: &Self, other: &BitSet) -> bool {
+        return self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0);
+    }
+
+    /// The members, in increasing order.
+    pub fn iter(self: &Self) -> impl Iterator<Item = usize> + '_ {
+        self.words
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &word)| Bits(word).map(move |bit| i * 64 + bit))
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(items: I) -> Self {
+        let mut set = BitSet::new();
+        set.extend(items);
+
+        return set;
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, items: I) {
+        for i in items {
+            self.insert(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BitSet, BitSet64};
+
+    #[test]
+    fn bitset64_is_a_set() {
+        let mut set = BitSet64::new();
+        assert!(set.insert(3));
+        assert!(!set.insert(3));
+        set.insert(63);
+        set.insert(0);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![0, 3, 63]);
+        assert_eq!(set.len(), 3);
+        assert!(set.remove(3));
+        assert!(!set.contains(3));
+        assert!(!set.contains(64));
+
+        let other = [0, 1].into_iter().collect::<BitSet64>();
+        assert_eq!(set.intersection(other).bits(), 1);
+        assert_eq!(set.union(other).len(), 3);
+        assert_eq!(set.difference(other).iter().collect::<Vec<_>>(), vec![63]);
+        assert!(BitSet64::from_bits(0b10).is_subset(&other));
+        assert!(!set.is_disjoint(&other));
+        assert_eq!(BitSet64::full(3).bits(), 0b111);
+        assert_eq!(BitSet64::full(64).len(), 64);
+        assert_eq!(format!("{:?}", other), "{0, 1}");
+    }
+
+    #[test]
+    fn bitset_grows() {
+        let mut set = BitSet::with_capacity(10);
+        set.insert(5);
+        set.insert(130);
+
+        assert!(set.contains(130));
+        assert!(!set.contains(1000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![5, 130]);
+
+        let other = [5, 6, 200].into_iter().collect::<BitSet>();
+        let mut union = set.clone();
+        union.union_with(&other);
+        assert_eq!(union.iter().collect::<Vec<_>>(), vec![5, 6, 130, 200]);
+
+        let mut intersection = set.clone();
+        intersection.intersect_with(&other);
+        assert_eq!(intersection.iter().collect::<Vec<_>>(), vec![5]);
+
+        set.difference_with(&other);
+        assert_eq!(set.len(), 1);
+        assert!(set.is_disjoint(&other));
+
+        assert_eq!(set, [130].into_iter().collect::<BitSet>());
+
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!(set, BitSet::new());
+    }
+}
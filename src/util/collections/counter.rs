@@ -0,0 +1,122 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+
+/// A multiset: how many times each item was added.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Eq + Hash> {
+    counts: HashMap<T, usize>,
+}
+
+impl<T: Eq + Hash> Default for Counter<T> {
+    fn default() -> Self {
+        return Counter {
+            counts: HashMap::new(),
+        };
+    }
+}
+
+impl<T: Eq + Hash> Counter<T> {
+    pub fn new() -> Counter<T> {
+        return Counter::default();
+    }
+
+    pub fn add(self: &mut Self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    pub fn add_n(self: &mut Self, item: T, n: usize) {
+        *self.counts.entry(item).or_insert(0) += n;
+    }
+
+    /// How many times `item` was added; 0 for items that never were.
+    pub fn get(self: &Self, item: &T) -> usize {
+        return self.counts.get(item).copied().unwrap_or(0);
+    }
+
+    /// The number of distinct items.
+    pub fn len(self: &Self) -> usize {
+        return self.counts.len();
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        return self.counts.is_empty();
+    }
+
+    /// The number of items added, counting repetitions.
+    pub fn total(self: &Self) -> usize {
+        return self.counts.values().sum();
+    }
+
+    /// The distinct items with their count, in no particular order.
+    pub fn iter(self: &Self) -> hash_map::Iter<'_, T, usize> {
+        return self.counts.iter();
+    }
+
+    /// The items added exactly `n` times.
+    pub fn with_count(self: &Self, n: usize) -> impl Iterator<Item = &T> {
+        self.counts
+            .iter()
+            .filter(move |(_, &count)| count == n)
+            .map(|(item, _)| item)
+    }
+
+    /// The distinct items by decreasing count; ties keep no particular order.
+    pub fn most_common(self: &Self) -> Vec<(&T, usize)> {
+        let mut items = self
+            .counts
+            .iter()
+            .map(|(item, &count)| (item, count))
+            .collect::<Vec<_>>();
+        items.sort_by_key(|&(_, count)| Reverse(count));
+
+        return items;
+    }
+}
+
+impl<T: Eq + Hash> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Eq + Hash> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(items);
+
+        return counter;
+    }
+}
+
+impl<T: Eq + Hash> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = hash_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        return self.counts.into_iter();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+
+    #[test]
+    fn counts_every_item() {
+        let mut counter = "abracadabra".chars().collect::<Counter<_>>();
+        counter.add_n('z', 2);
+
+        assert_eq!(counter.get(&'a'), 5);
+        assert_eq!(counter.get(&'q'), 0);
+        assert_eq!(counter.len(), 6);
+        assert_eq!(counter.total(), 13);
+        assert_eq!(counter.most_common()[0], (&'a', 5));
+
+        let mut twice = counter.with_count(2).copied().collect::<Vec<_>>();
+        twice.sort();
+        assert_eq!(twice, vec!['b', 'r', 'z']);
+    }
+}
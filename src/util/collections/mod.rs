@@ -0,0 +1,39 @@
+//! Data structures shared by the puzzles of every event.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+mod bitset;
+mod counter;
+mod sequence;
+mod union_find;
+
+pub use bitset::{BitSet, BitSet64, Bits};
+pub use counter::Counter;
+pub use sequence::{Id, Iter, Sequence};
+pub use union_find::UnionFind;
+
+/// The distinct items, in the order of their first occurrence.
+pub fn unique<T>(items: impl IntoIterator<Item = T>) -> Vec<T>
+where
+    T: Eq + Hash + Clone,
+{
+    let mut seen = HashSet::new();
+
+    return items
+        .into_iter()
+        .filter(|item| seen.insert(item.clone()))
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn unique_keeps_the_first_occurrences_in_order() {
+        assert_eq!(super::unique([3, 1, 3, 2, 1]), vec![3, 1, 2]);
+        assert_eq!(
+            super::unique(["b".to_string(), "a".to_string(), "b".to_string()]),
+            vec!["b".to_string(), "a".to_string()]
+        );
+    }
+}
@@ -0,0 +1,366 @@
+use std::fmt;
+
+const NIL: usize = usize::MAX;
+
+/// Names an element of a [`Sequence`] for as long as it stays in it, wherever
+/// it moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(usize);
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: Option<T>,
+    priority: u64,
+    size: usize,
+    left: usize,
+    right: usize,
+    parent: usize,
+}
+
+/// A list with O(log n) access, insertion and removal at any index, and
+/// O(log n) lookup of the index of an element by its [`Id`].
+///
+/// It is an implicit treap: a binary tree ordered by position, balanced by
+/// random priorities.
+#[derive(Clone)]
+pub struct Sequence<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    root: usize,
+    seed: u64,
+}
+
+impl<T> Default for Sequence<T> {
+    fn default() -> Self {
+        return Sequence {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            seed: 0x2545f4914f6cdd1d,
+        };
+    }
+}
+
+impl<T> Sequence<T> {
+    pub fn new() -> Sequence<T> {
+        return Sequence::default();
+    }
+
+    pub fn len(self: &Self) -> usize {
+        return self.size(self.root);
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        return self.root == NIL;
+    }
+
+    fn size(self: &Self, node: usize) -> usize {
+        match node {
+            NIL => 0,
+            _ => self.nodes[node].size,
+        }
+    }
+
+    fn random(self: &mut Self) -> u64 {
+        // xorshift64
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+
+        return self.seed;
+    }
+
+    fn update(self: &mut Self, node: usize) {
+        let (left, right) = (self.nodes[node].left, self.nodes[node].right);
+        self.nodes[node].size = self.size(left) + self.size(right) + 1;
+
+        if left != NIL {
+            self.nodes[left].parent = node;
+        }
+        if right != NIL {
+            self.nodes[right].parent = node;
+        }
+    }
+
+    /// Splits the tree of `node` into its first `k` elements and the rest.
+    fn split(self: &mut Self, node: usize, k: usize) -> (usize, usize) {
+        if node == NIL {
+            return (NIL, NIL);
+        }
+
+        let left = self.nodes[node].left;
+        if self.size(left) < k {
+            let right = self.nodes[node].right;
+            let (a, b) = self.split(right, k - self.size(left) - 1);
+            self.nodes[node].right = a;
+            self.update(node);
+            if b != NIL {
+                self.nodes[b].parent = NIL;
+            }
+            return (node, b);
+        } else {
+            let (a, b) = self.split(left, k);
+            self.nodes[node].left = b;
+            self.update(node);
+            if a != NIL {
+                self.nodes[a].parent = NIL;
+            }
+            return (a, node);
+        }
+    }
+
+    fn merge(self: &mut Self, a: usize, b: usize) -> usize {
+        if a == NIL || b == NIL {
+            return if a == NIL { b } else { a };
+        }
+
+        if self.nodes[a].priority > self.nodes[b].priority {
+            let right = self.nodes[a].right;
+            self.nodes[a].right = self.merge(right, b);
+            self.update(a);
+            return a;
+        } else {
+            let left = self.nodes[b].left;
+            self.nodes[b].left = self.merge(a, left);
+            self.update(b);
+            return b;
+        }
+    }
+
+    fn set_root(self: &mut Self, root: usize) {
+        self.root = root;
+        if root != NIL {
+            self.nodes[root].parent = NIL;
+        }
+    }
+
+    /// Puts the detached `node` at `index`.
+    fn attach(self: &mut Self, index: usize, node: usize) {
+        let (a, b) = self.split(self.root, index);
+        let a = self.merge(a, node);
+        let root = self.merge(a, b);
+        self.set_root(root);
+    }
+
+    /// Takes the node at `index` out of the tree.
+    fn detach(self: &mut Self, index: usize) -> usize {
+        let (a, b) = self.split(self.root, index);
+        let (node, c) = self.split(b, 1);
+        let root = self.merge(a, c);
+        self.set_root(root);
+
+        return node;
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it. Panics
+    /// when `index > len`.
+    pub fn insert(self: &mut Self, index: usize, value: T) -> Id {
+        assert!(index <= self.len(), "index {} out of range", index);
+
+        let node = Node {
+            value: Some(value),
+            priority: self.random(),
+            size: 1,
+            left: NIL,
+            right: NIL,
+            parent: NIL,
+        };
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.nodes[id] = node;
+                id
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        self.attach(index, id);
+
+        return Id(id);
+    }
+
+    pub fn push(self: &mut Self, value: T) -> Id {
+        return self.insert(self.len(), value);
+    }
+
+    /// Removes the element at `index`. Panics when `index >= len`.
+    pub fn remove(self: &mut Self, index: usize) -> T {
+        assert!(index < self.len(), "index {} out of range", index);
+
+        let node = self.detach(index);
+        self.free.push(node);
+
+        return self.nodes[node]
+            .value
+            .take()
+            .expect("live nodes to hold a value");
+    }
+
+    /// Moves the element at `from` so that it ends up at index `to`, keeping
+    /// its [`Id`].
+    pub fn relocate(self: &mut Self, from: usize, to: usize) {
+        assert!(from < self.len() && to < self.len(), "index out of range");
+
+        let node = self.detach(from);
+        self.attach(to, node);
+    }
+
+    fn node_at(self: &Self, index: usize) -> Option<usize> {
+        let mut node = self.root;
+        let mut index = index;
+
+        while node != NIL {
+            let left = self.size(self.nodes[node].left);
+            if index < left {
+                node = self.nodes[node].left;
+            } else if index == left {
+                return Some(node);
+            } else {
+                index -= left + 1;
+                node = self.nodes[node].right;
+            }
+        }
+
+        return None;
+    }
+
+    pub fn get(self: &Self, index: usize) -> Option<&T> {
+        let node = self.node_at(index)?;
+        return self.nodes[node].value.as_ref();
+    }
+
+    pub fn get_mut(self: &mut Self, index: usize) -> Option<&mut T> {
+        let node = self.node_at(index)?;
+        return self.nodes[node].value.as_mut();
+    }
+
+    /// The id of the element at `index`.
+    pub fn id(self: &Self, index: usize) -> Option<Id> {
+        return self.node_at(index).map(Id);
+    }
+
+    /// The element named `id`, if it is still in the sequence.
+    pub fn by_id(self: &Self, id: Id) -> Option<&T> {
+        return self.nodes.get(id.0)?.value.as_ref();
+    }
+
+    /// The current index of the element named `id`, if it is still in the
+    /// sequence.
+    pub fn position(self: &Self, id: Id) -> Option<usize> {
+        let mut node = id.0;
+        self.nodes.get(node)?.value.as_ref()?;
+
+        let mut index = self.size(self.nodes[node].left);
+        while self.nodes[node].parent != NIL {
+            let parent = self.nodes[node].parent;
+            if self.nodes[parent].right == node {
+                index += self.size(self.nodes[parent].left) + 1;
+            }
+            node = parent;
+        }
+
+        return Some(index);
+    }
+
+    /// The elements, in order.
+    pub fn iter(self: &Self) -> Iter<'_, T> {
+        let mut iter = Iter {
+            sequence: self,
+            stack: Vec::new(),
+        };
+        iter.push_left(self.root);
+
+        return iter;
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Sequence<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> FromIterator<T> for Sequence<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut sequence = Sequence::new();
+        for item in items {
+            sequence.push(item);
+        }
+
+        return sequence;
+    }
+}
+
+/// The elements of a [`Sequence`], in order.
+pub struct Iter<'a, T> {
+    sequence: &'a Sequence<T>,
+    stack: Vec<usize>,
+}
+
+impl<T> Iter<'_, T> {
+    fn push_left(self: &mut Self, node: usize) {
+        let mut node = node;
+        while node != NIL {
+            self.stack.push(node);
+            node = self.sequence.nodes[node].left;
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.stack.pop()?;
+        self.push_left(self.sequence.nodes[node].right);
+
+        return self.sequence.nodes[node].value.as_ref();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sequence;
+
+    #[test]
+    fn behaves_like_a_vec() {
+        let mut sequence = Sequence::new();
+        let mut vec = Vec::new();
+
+        for i in 0..200usize {
+            let index = (i * 7919) % (vec.len() + 1);
+            sequence.insert(index, i);
+            vec.insert(index, i);
+
+            if i % 3 == 0 {
+                let index = (i * 104729) % vec.len();
+                assert_eq!(sequence.remove(index), vec.remove(index));
+            }
+        }
+
+        assert_eq!(sequence.len(), vec.len());
+        assert_eq!(sequence.iter().copied().collect::<Vec<_>>(), vec);
+        assert_eq!(sequence.get(10), vec.get(10));
+        assert_eq!(sequence.get(vec.len()), None);
+    }
+
+    #[test]
+    fn ids_follow_their_element() {
+        let mut sequence = "abcde".chars().collect::<Sequence<_>>();
+        let c = sequence.id(2).unwrap();
+
+        sequence.relocate(2, 0);
+        assert_eq!(sequence.position(c), Some(0));
+        assert_eq!(sequence.iter().collect::<String>(), "cabde");
+
+        sequence.relocate(0, 4);
+        assert_eq!(sequence.position(c), Some(4));
+        assert_eq!(sequence.by_id(c), Some(&'c'));
+
+        sequence.remove(4);
+        assert_eq!(sequence.position(c), None);
+        assert_eq!(sequence.iter().collect::<String>(), "abde");
+    }
+}
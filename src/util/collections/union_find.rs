@@ -0,0 +1,96 @@
+/// Disjoint sets over `0..n`, with union by size and path compression.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> UnionFind {
+        return UnionFind {
+            parents: (0..n).collect(),
+            sizes: vec![1; n],
+            count: n,
+        };
+    }
+
+    /// The representative of the set of `x`.
+    pub fn find(self: &mut Self, x: usize) -> usize {
+        let mut root = x;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut x = x;
+        while self.parents[x] != root {
+            let next = self.parents[x];
+            self.parents[x] = root;
+            x = next;
+        }
+
+        return root;
+    }
+
+    /// Merges the sets of `a` and `b`, returning whether they were apart.
+    pub fn union(self: &mut Self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        if self.sizes[a] < self.sizes[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+        self.count -= 1;
+
+        return true;
+    }
+
+    pub fn same(self: &mut Self, a: usize, b: usize) -> bool {
+        return self.find(a) == self.find(b);
+    }
+
+    /// The size of the set of `x`.
+    pub fn size(self: &mut Self, x: usize) -> usize {
+        let root = self.find(x);
+        return self.sizes[root];
+    }
+
+    /// The number of sets.
+    pub fn count(self: &Self) -> usize {
+        return self.count;
+    }
+
+    pub fn len(self: &Self) -> usize {
+        return self.parents.len();
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        return self.parents.is_empty();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnionFind;
+
+    #[test]
+    fn union_merges_sets() {
+        let mut sets = UnionFind::new(6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+
+        assert!(sets.same(0, 3));
+        assert!(!sets.same(0, 4));
+        assert_eq!(sets.size(2), 4);
+        assert_eq!(sets.size(5), 1);
+        assert_eq!(sets.count(), 3);
+    }
+}
@@ -0,0 +1,503 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+
+/// The configuration file that is picked up from the working directory.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// How the runner prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// `Part 1: ...` lines, the way the binaries always printed them.
+    #[default]
+    Text,
+    /// One JSON report per day.
+    Json,
+}
+
+/// The named parameters of a single day, e.g. `y_level_1` for day 15.
+///
+/// Values are kept as strings and parsed on lookup, so the same table can hold
+/// numbers coming from `aoc.toml` and strings coming from the command line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    /// Returns the parameter `name`, or `default` when it was not configured.
+    ///
+    /// Panics when the configured value cannot be parsed as `T`.
+    pub fn get<T>(&self, name: &str, default: T) -> T
+    where
+        T: FromStr,
+        <T as FromStr>::Err: Debug,
+    {
+        match self.0.get(name) {
+            None => default,
+            Some(value) => value.parse::<T>().unwrap_or_else(|e| {
+                panic!("invalid value {:?} for parameter {}: {:?}", value, name, e)
+            }),
+        }
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// The parameters, ordered by name.
+    pub fn entries(&self) -> std::collections::BTreeMap<&str, &str> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect()
+    }
+
+    fn extend(&mut self, other: &Params) {
+        self.0
+            .extend(other.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+    }
+}
+
+/// Options shared by every binary; they override the values from `aoc.toml`.
+#[derive(Debug, Default, Clone, clap::Parser)]
+pub struct Args {
+    /// Configuration file [default: aoc.toml, when present]
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// Read the puzzle input from this file instead of stdin or the input directory
+    #[arg(short, long)]
+    pub input: Option<PathBuf>,
+
+    /// Directory holding the `YEAR/dayNN.input` files
+    #[arg(long)]
+    pub input_dir: Option<PathBuf>,
+
+    /// The event to run [default: the latest one]
+    #[arg(long)]
+    pub year: Option<u32>,
+
+    /// File holding the known answers
+    #[arg(long)]
+    pub answers: Option<PathBuf>,
+
+    /// The site to download inputs from [default: https://adventofcode.com]
+    #[arg(long)]
+    pub base_url: Option<String>,
+
+    /// Time limit for each part, in seconds
+    #[arg(long)]
+    pub timeout: Option<u64>,
+
+    /// Solve again instead of reusing cached answers, and refresh the cache
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Empty the cache of answers before running
+    #[arg(long)]
+    pub clear_cache: bool,
+
+    /// Output format
+    #[arg(long, value_enum)]
+    pub format: Option<Format>,
+
+    /// Write more to the debug channel, up to the dumps of the puzzles with
+    /// `-vv`; repeatable
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Write less to the debug channel, only the errors with `-q`; repeatable
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    pub quiet: u8,
+
+    /// Write the debug channel to this file instead of stderr
+    #[arg(long)]
+    pub log: Option<PathBuf>,
+
+    /// Override a puzzle parameter; without the `[YEAR.]dayNN.` prefix it applies to every day
+    #[arg(short, long = "param", value_name = "[[YEAR.]DAY.]NAME=VALUE")]
+    pub params: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub year: u32,
+    pub input: Option<PathBuf>,
+    pub input_dir: PathBuf,
+    pub answers: PathBuf,
+    /// Every answer submitted to the site, see [`crate::submit::History`].
+    pub history: PathBuf,
+    /// The answers and timings of earlier runs, see [`crate::cache::Cache`].
+    pub cache: PathBuf,
+    /// Solve every day again, ignoring the cache.
+    pub no_cache: bool,
+    pub clear_cache: bool,
+    pub timeout: Option<Duration>,
+    pub format: Format,
+    /// The command of another solver, for `aoc compare`; see
+    /// [`crate::reference::command`].
+    pub reference: Option<String>,
+    /// What the debug channel writes, see [`crate::log`].
+    pub log_level: crate::log::Level,
+    /// Where the debug channel writes; stderr when missing.
+    pub log: Option<PathBuf>,
+
+    pub base_url: String,
+    /// The session cookie; `AOC_SESSION` is used when it is not configured.
+    pub session: Option<String>,
+    /// The least time between two requests to the site.
+    pub request_interval: Duration,
+
+    /// Keyed by `dayNN` for every event and by `YEAR.dayNN` for a single one.
+    params: HashMap<String, Params>,
+    overrides: Params,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        return Config {
+            year: crate::registry::years().last().copied().unwrap_or_default(),
+            input: None,
+            input_dir: PathBuf::from("input"),
+            answers: PathBuf::from("answers.toml"),
+            history: PathBuf::from("history.toml"),
+            cache: PathBuf::from("target/aoc-cache.json"),
+            no_cache: false,
+            clear_cache: false,
+            timeout: None,
+            format: Format::default(),
+            reference: None,
+            log_level: crate::log::Level::default(),
+            log: None,
+            base_url: crate::client::BASE_URL.to_string(),
+            session: None,
+            request_interval: Duration::from_secs(1),
+            params: HashMap::new(),
+            overrides: Params::default(),
+        };
+    }
+}
+
+/// The on-disk layout of `aoc.toml`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct File {
+    year: Option<u32>,
+    input_dir: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    history: Option<PathBuf>,
+    cache: Option<PathBuf>,
+    timeout: Option<u64>,
+    format: Option<Format>,
+    reference: Option<String>,
+    log_level: Option<crate::log::Level>,
+    log: Option<PathBuf>,
+    base_url: Option<String>,
+    session: Option<String>,
+    request_interval: Option<u64>,
+    params: HashMap<String, toml::Table>,
+}
+
+pub fn day_key(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+fn param_value(name: &str, value: toml::Value) -> Result<String, Error> {
+    return match value {
+        toml::Value::String(s) => Ok(s),
+        toml::Value::Integer(i) => Ok(i.to_string()),
+        toml::Value::Float(f) => Ok(f.to_string()),
+        toml::Value::Boolean(b) => Ok(b.to_string()),
+        other => Err(Error::ConfigError(format!(
+            "unsupported value {} for parameter {}",
+            other, name
+        ))),
+    };
+}
+
+fn param_table(values: toml::Table) -> Result<Params, Error> {
+    let mut params = Params::default();
+    for (name, value) in values {
+        params.set(name.clone(), param_value(&name, value)?);
+    }
+
+    return Ok(params);
+}
+
+impl FromStr for Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let file = toml::from_str::<File>(s).map_err(|e| Error::ConfigError(e.to_string()))?;
+        let mut config = Config::default();
+
+        if let Some(year) = file.year {
+            config.year = year;
+        }
+        if let Some(input_dir) = file.input_dir {
+            config.input_dir = input_dir;
+        }
+        if let Some(answers) = file.answers {
+            config.answers = answers;
+        }
+        if let Some(history) = file.history {
+            config.history = history;
+        }
+        if let Some(cache) = file.cache {
+            config.cache = cache;
+        }
+        config.timeout = file.timeout.map(Duration::from_secs);
+        config.format = file.format.unwrap_or_default();
+        config.reference = file.reference;
+        config.log_level = file.log_level.unwrap_or_default();
+        config.log = file.log;
+        if let Some(base_url) = file.base_url {
+            config.base_url = base_url;
+        }
+        config.session = file.session;
+        if let Some(interval) = file.request_interval {
+            config.request_interval = Duration::from_secs(interval);
+        }
+
+        for (key, values) in file.params {
+            // `[params.2022.day15]` is a table of days, `[params.day15]` one of values.
+            if key.parse::<u32>().is_err() {
+                config.params.insert(key, param_table(values)?);
+                continue;
+            }
+
+            for (day, values) in values {
+                let scope = format!("{}.{}", key, day);
+                let values = match values {
+                    toml::Value::Table(values) => values,
+                    _ => {
+                        return Err(Error::ConfigError(format!(
+                            "expected a table for {}",
+                            scope
+                        )))
+                    }
+                };
+                config.params.insert(scope, param_table(values)?);
+            }
+        }
+
+        return Ok(config);
+    }
+}
+
+impl Config {
+    /// Loads the configuration file named by `args` (or `aoc.toml`, if it
+    /// exists) and applies the command line overrides on top of it.
+    pub fn load(args: &Args) -> Result<Config, Error> {
+        let mut config = match &args.config {
+            Some(path) => Config::from_file(path)?,
+            None if Path::new(CONFIG_FILE).exists() => Config::from_file(CONFIG_FILE)?,
+            None => Config::default(),
+        };
+
+        config.apply(args)?;
+
+        return Ok(config);
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Config, Error> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| Error::ConfigError(format!("failed to read {}: {}", path.display(), e)))?;
+
+        return text.parse::<Config>();
+    }
+
+    pub fn apply(&mut self, args: &Args) -> Result<(), Error> {
+        if let Some(input) = &args.input {
+            self.input = Some(input.clone());
+        }
+        if let Some(year) = args.year {
+            self.year = year;
+        }
+        if let Some(input_dir) = &args.input_dir {
+            self.input_dir = input_dir.clone();
+        }
+        if let Some(answers) = &args.answers {
+            self.answers = answers.clone();
+        }
+        if let Some(timeout) = args.timeout {
+            self.timeout = Some(Duration::from_secs(timeout));
+        }
+        self.no_cache |= args.no_cache;
+        self.clear_cache |= args.clear_cache;
+        if let Some(format) = args.format {
+            self.format = format;
+        }
+        if let Some(base_url) = &args.base_url {
+            self.base_url = base_url.clone();
+        }
+        self.log_level = crate::log::Level::adjust(self.log_level, args.verbose, args.quiet);
+        if let Some(log) = &args.log {
+            self.log = Some(log.clone());
+        }
+
+        for param in &args.params {
+            let (key, value) = param.split_once('=').ok_or(Error::ConfigError(format!(
+                "expected NAME=VALUE, found {}",
+                param
+            )))?;
+
+            match key.rsplit_once('.') {
+                Some((scope, name)) => self
+                    .params
+                    .entry(scope.to_string())
+                    .or_default()
+                    .set(name, value),
+                None => self.overrides.set(key, value),
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Points the debug channel of [`crate::log`] where this configuration
+    /// says.
+    pub fn init_log(&self) -> Result<(), Error> {
+        return crate::log::init(self.log_level, self.log.as_deref());
+    }
+
+    /// A client for the configured site; fails when there is no session to log
+    /// in with.
+    pub fn client(&self) -> Result<crate::client::Client, Error> {
+        let session = match &self.session {
+            Some(session) => session.clone(),
+            None => std::env::var(crate::client::SESSION_VAR).map_err(|_| {
+                Error::ConfigError(format!(
+                    "no session configured, set `session` in {} or {}",
+                    CONFIG_FILE,
+                    crate::client::SESSION_VAR
+                ))
+            })?,
+        };
+
+        return Ok(crate::client::Client::new(
+            &self.base_url,
+            &session,
+            self.request_interval,
+        ));
+    }
+
+    /// The parameters of `day` of `year`, with the command line overrides
+    /// applied. Those given for the day of a single event win over those given
+    /// for that day of every event.
+    pub fn params(&self, year: u32, day: u32) -> Params {
+        let mut params = self.params.get(&day_key(day)).cloned().unwrap_or_default();
+        if let Some(specific) = self.params.get(&format!("{}.{}", year, day_key(day))) {
+            params.extend(specific);
+        }
+        params.extend(&self.overrides);
+
+        return params;
+    }
+
+    /// Where the input of `day` of `year` is stored in the input directory:
+    /// `YEAR/dayNN.input`, or the `dayNN.input` of the single-year layout when
+    /// only that one exists.
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        let name = format!("{}.input", day_key(day));
+        let path = self.input_dir.join(year.to_string()).join(&name);
+
+        let legacy = self.input_dir.join(&name);
+        if !path.exists() && legacy.exists() {
+            return legacy;
+        }
+
+        return path;
+    }
+
+    /// Where the example of the puzzle text of `day` of `year` is kept:
+    /// `YEAR/dayNN.example` in the input directory.
+    pub fn example_path(&self, year: u32, day: u32) -> PathBuf {
+        return self
+            .input_dir
+            .join(year.to_string())
+            .join(format!("{}.example", day_key(day)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Args, Config, Format};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    #[test]
+    fn from_str_reads_every_section() {
+        let config = "year = 2021
+input_dir = \"examples\"
+timeout = 10
+format = \"json\"
+
+[params.day15]
+y_level_1 = 10
+y_level_2 = 20
+
+[params.2022.day15]
+y_level_2 = 30
+"
+        .parse::<Config>()
+        .unwrap();
+
+        assert_eq!(config.year, 2021);
+        assert_eq!(config.input_dir, PathBuf::from("examples"));
+        assert_eq!(config.timeout, Some(Duration::from_secs(10)));
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.params(2021, 15).get("y_level_1", 2000000), 10);
+        assert_eq!(config.params(2021, 15).get("y_level_2", 4000000), 20);
+        assert_eq!(config.params(2022, 15).get("y_level_1", 2000000), 10);
+        assert_eq!(config.params(2022, 15).get("y_level_2", 4000000), 30);
+        assert_eq!(
+            config.params(2022, 7).get("total_space", 70000000),
+            70000000
+        );
+        assert_eq!(
+            config.input_path(2022, 15),
+            PathBuf::from("examples/2022/day15.input")
+        );
+    }
+
+    #[test]
+    fn apply_overrides_the_file() {
+        let mut config = "log_level = \"info\"\n[params.day15]\ny_level_1 = 10"
+            .parse::<Config>()
+            .unwrap();
+
+        config
+            .apply(&Args {
+                year: Some(2021),
+                timeout: Some(5),
+                verbose: 2,
+                params: vec![
+                    "day15.y_level_1=11".to_string(),
+                    "2021.day15.y_level_1=12".to_string(),
+                    "minutes=3".to_string(),
+                ],
+                ..Args::default()
+            })
+            .unwrap();
+
+        assert_eq!(config.year, 2021);
+        assert_eq!(config.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(config.log_level, crate::log::Level::Trace);
+        assert_eq!(config.params(2022, 15).get("y_level_1", 0), 11);
+        assert_eq!(config.params(2021, 15).get("y_level_1", 0), 12);
+        assert_eq!(config.params(2022, 16).get("minutes", 30), 3);
+    }
+
+    #[test]
+    fn from_str_rejects_unknown_keys() {
+        assert!("inputs = \"input\"".parse::<Config>().is_err());
+    }
+}
@@ -0,0 +1,539 @@
+//! `aoc dashboard`: every day of a year in a terminal UI, with its status,
+//! answers, timings and example tests.
+//!
+//! Days are solved by `cargo run --bin aoc -- run` and their examples by
+//! `cargo test`, in the background, so that an edit to a day is picked up the
+//! next time it runs.
+
+mod ui;
+
+use std::process::Command;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+
+use crate::answers::{Answers, Verdict};
+use crate::cache::Cache;
+use crate::config::{day_key, Args, Config};
+use crate::error::Error;
+use crate::registry::{self, Entry};
+use crate::runner::{Report, Status as Stage};
+
+pub use ui::main;
+
+/// Where a day stands, from its last run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    NotRun,
+    Running,
+    /// There is no input to run it on.
+    NoInput,
+    /// Every part has an answer, but some are not in the answer store.
+    Solved,
+    /// Every answer matches the answer store.
+    Verified,
+    /// Some stage failed or timed out, or an answer is wrong.
+    Failing,
+}
+
+impl Status {
+    /// The status of a run that produced `report`, given how its answers
+    /// compare to the stored ones.
+    pub fn of(report: &Report, verdicts: &[Verdict]) -> Status {
+        if !report.is_solved() || verdicts.iter().any(|v| matches!(v, Verdict::Wrong { .. })) {
+            return Status::Failing;
+        }
+
+        if verdicts.iter().all(|v| *v == Verdict::Correct) {
+            return Status::Verified;
+        }
+
+        return Status::Solved;
+    }
+
+    pub fn label(self: &Self) -> &'static str {
+        match self {
+            Status::NotRun => "-",
+            Status::Running => "running",
+            Status::NoInput => "no input",
+            Status::Solved => "solved",
+            Status::Verified => "verified",
+            Status::Failing => "failing",
+        }
+    }
+}
+
+/// The outcome of the example tests of a day, from `cargo test`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub passed: u32,
+    pub failed: u32,
+    pub ignored: u32,
+    /// What `cargo test` printed, to show the failures.
+    pub output: String,
+}
+
+impl Examples {
+    /// Sums the `test result:` lines of the output of `cargo test`; `None`
+    /// when there is none, e.g. because the crate did not build.
+    pub fn parse(output: &str) -> Option<Examples> {
+        let mut examples = Examples {
+            output: output.to_string(),
+            ..Examples::default()
+        };
+        let mut found = false;
+
+        for line in output.lines() {
+            let Some(summary) = line.strip_prefix("test result: ") else {
+                continue;
+            };
+            found = true;
+
+            for count in summary.split(['.', ';']) {
+                let mut words = count.split_whitespace();
+                let (Some(n), Some(what)) = (words.next(), words.next()) else {
+                    continue;
+                };
+                let Ok(n) = n.parse::<u32>() else {
+                    continue;
+                };
+                match what {
+                    "passed" => examples.passed += n,
+                    "failed" => examples.failed += n,
+                    "ignored" => examples.ignored += n,
+                    _ => (),
+                }
+            }
+        }
+
+        return found.then_some(examples);
+    }
+
+    pub fn label(self: &Self) -> String {
+        match (self.passed, self.failed) {
+            (0, 0) => "none".to_string(),
+            (passed, 0) => format!("{} ok", passed),
+            (passed, failed) => format!("{}/{} failed", failed, passed + failed),
+        }
+    }
+}
+
+/// A line of a diff between a stored answer and a new one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Same(String),
+    /// Only in the stored answer.
+    Removed(String),
+    /// Only in the new answer.
+    Added(String),
+}
+
+/// The line diff from `expected` to `actual`, as the longest common
+/// subsequence of their lines.
+pub fn diff(expected: &str, actual: &str) -> Vec<Line> {
+    let a = expected.lines().collect::<Vec<_>>();
+    let b = actual.lines().collect::<Vec<_>>();
+
+    // lcs[i][j] is the length of the common subsequence of a[i..] and b[j..].
+    let mut lcs = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = match a[i] == b[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            lines.push(Line::Same(a[i].to_string()));
+            (i, j) = (i + 1, j + 1);
+        } else if j < b.len() && (i == a.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            lines.push(Line::Added(b[j].to_string()));
+            j += 1;
+        } else {
+            lines.push(Line::Removed(a[i].to_string()));
+            i += 1;
+        }
+    }
+
+    return lines;
+}
+
+/// A day of the dashboard.
+#[derive(Debug, Clone)]
+pub struct Day {
+    pub entry: &'static Entry,
+    pub status: Status,
+    /// The last run, or the cached one until the day is run.
+    pub report: Option<Report>,
+    pub verdicts: Vec<Verdict>,
+    /// What the last run printed besides its report: wrong answers, debug
+    /// output and panics.
+    pub messages: String,
+    pub examples: Option<Examples>,
+    pub testing: bool,
+}
+
+/// Work for the background thread.
+#[derive(Debug, Clone, Copy)]
+enum Job {
+    Run(usize),
+    Examples(usize),
+}
+
+/// The result of a [`Job`], for the day at that index.
+#[derive(Debug)]
+enum Done {
+    Run(usize, Result<Report, String>, String),
+    Examples(usize, Result<Examples, String>),
+}
+
+/// The days of a year and the runs in progress.
+pub struct Dashboard {
+    pub config: Config,
+    pub days: Vec<Day>,
+    pub answers: Answers,
+    jobs: Sender<Job>,
+    done: Receiver<Done>,
+}
+
+/// The command that runs `aoc` with the options of the dashboard: through
+/// cargo from the root of the crate, so that the code is rebuilt after an
+/// edit, with the profile and the features of the dashboard so that only an
+/// edit rebuilds it; the running binary elsewhere.
+fn aoc_command(config: &Config, args: &Args) -> Command {
+    let mut command = match std::path::Path::new("Cargo.toml").exists() {
+        true => {
+            let mut cargo = Command::new("cargo");
+            cargo.args(["run", "--quiet", "--features", "tui"]);
+            if !cfg!(debug_assertions) {
+                cargo.arg("--release");
+            }
+            cargo.args(["--bin", "aoc", "--"]);
+            cargo
+        }
+        false => Command::new(std::env::current_exe().unwrap_or_else(|_| "aoc".into())),
+    };
+
+    command.args([
+        "run",
+        "--format",
+        "json",
+        "--year",
+        &config.year.to_string(),
+    ]);
+    if let Some(file) = &args.config {
+        command.arg("--config").arg(file);
+    }
+    if let Some(dir) = &args.input_dir {
+        command.arg("--input-dir").arg(dir);
+    }
+    if let Some(answers) = &args.answers {
+        command.arg("--answers").arg(answers);
+    }
+    if let Some(timeout) = args.timeout {
+        command.args(["--timeout", &timeout.to_string()]);
+    }
+    if args.no_cache {
+        command.arg("--no-cache");
+    }
+    for param in &args.params {
+        command.args(["--param", param]);
+    }
+
+    return command;
+}
+
+fn run_day(mut command: Command, day: u32) -> (Result<Report, String>, String) {
+    let output = match command.arg(day.to_string()).output() {
+        Ok(output) => output,
+        Err(e) => return (Err(e.to_string()), String::new()),
+    };
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    let report = stdout
+        .lines()
+        .rev()
+        .find_map(|line| serde_json::from_str::<Report>(line).ok())
+        .ok_or_else(|| match stderr.trim().is_empty() {
+            true => format!("no report in {:?}", stdout),
+            false => stderr.clone(),
+        });
+
+    return (report, stderr);
+}
+
+fn run_examples(year: u32, day: u32) -> Result<Examples, String> {
+    let filter = format!("y{}::{}::", year, day_key(day));
+    let output = Command::new("cargo")
+        .args(["test", "--quiet", "--lib", &filter])
+        .output()
+        .map_err(|e| e.to_string())?;
+
+    let text = String::from_utf8_lossy(&output.stdout).to_string()
+        + &String::from_utf8_lossy(&output.stderr);
+
+    return Examples::parse(&text).ok_or(text);
+}
+
+impl Dashboard {
+    /// The days of the year of `args`, with the answers of their last run
+    /// when it is still in the cache.
+    pub fn new(args: Args) -> Result<Dashboard, Error> {
+        let config = Config::load(&args)?;
+        let answers = Answers::load(&config.answers)?;
+        let cache = Cache::load(&config.cache);
+
+        let days = registry::year(config.year)
+            .map(|entry| {
+                let input = crate::input::read_file(config.input_path(entry.year, entry.day));
+                let report = input.as_ref().ok().and_then(|input| {
+                    let params = config.params(entry.year, entry.day);
+                    let key = crate::cache::input_key(input, &params);
+                    // The dashboard shows no pictures.
+                    cache.get(entry.year, entry.day, entry.parts, false, &key)
+                });
+
+                let mut day = Day {
+                    entry,
+                    status: match input {
+                        Ok(_) => Status::NotRun,
+                        Err(_) => Status::NoInput,
+                    },
+                    report: None,
+                    verdicts: Vec::new(),
+                    messages: String::new(),
+                    examples: None,
+                    testing: false,
+                };
+                if let Some(report) = report {
+                    day.verdicts = answers.verify(&report);
+                    day.status = Status::of(&report, &day.verdicts);
+                    day.report = Some(report);
+                }
+
+                day
+            })
+            .collect::<Vec<_>>();
+
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let (results, done) = mpsc::channel::<Done>();
+        let worker_config = config.clone();
+        let keys = days
+            .iter()
+            .map(|day| (day.entry.year, day.entry.day))
+            .collect::<Vec<_>>();
+
+        thread::spawn(move || {
+            for job in queue {
+                let done = match job {
+                    Job::Run(i) => {
+                        let command = aoc_command(&worker_config, &args);
+                        let (report, messages) = run_day(command, keys[i].1);
+                        Done::Run(i, report, messages)
+                    }
+                    Job::Examples(i) => Done::Examples(i, run_examples(keys[i].0, keys[i].1)),
+                };
+                if results.send(done).is_err() {
+                    return;
+                }
+            }
+        });
+
+        return Ok(Dashboard {
+            config,
+            days,
+            answers,
+            jobs,
+            done,
+        });
+    }
+
+    /// Solves the day at `i` again, in the background.
+    pub fn run(self: &mut Self, i: usize) {
+        if self.days[i].status == Status::Running {
+            return;
+        }
+
+        self.days[i].status = Status::Running;
+        let _ = self.jobs.send(Job::Run(i));
+    }
+
+    /// Runs the example tests of the day at `i`, in the background.
+    pub fn test(self: &mut Self, i: usize) {
+        if self.days[i].testing {
+            return;
+        }
+
+        self.days[i].testing = true;
+        let _ = self.jobs.send(Job::Examples(i));
+    }
+
+    /// Takes in the runs that finished since the last call; returns whether
+    /// there were any.
+    pub fn update(self: &mut Self) -> bool {
+        let mut updated = false;
+
+        while let Ok(done) = self.done.try_recv() {
+            updated = true;
+            match done {
+                Done::Run(i, report, messages) => {
+                    // The runs may have stored new answers.
+                    if let Ok(answers) = Answers::load(&self.config.answers) {
+                        self.answers = answers;
+                    }
+
+                    let day = &mut self.days[i];
+                    day.messages = messages;
+                    match report {
+                        Ok(report) => {
+                            day.verdicts = self.answers.verify(&report);
+                            day.status = Status::of(&report, &day.verdicts);
+                            day.report = Some(report);
+                        }
+                        Err(e) => {
+                            day.messages = e;
+                            day.status = Status::Failing;
+                        }
+                    }
+                }
+                Done::Examples(i, examples) => {
+                    let day = &mut self.days[i];
+                    day.testing = false;
+                    day.examples = Some(examples.unwrap_or_else(|output| Examples {
+                        failed: 1,
+                        output,
+                        ..Examples::default()
+                    }));
+                }
+            }
+        }
+
+        return updated;
+    }
+
+    /// Stores the answers of the last run of the day at `i` as the accepted
+    /// ones.
+    pub fn accept(self: &mut Self, i: usize) -> Result<(), Error> {
+        let day = &mut self.days[i];
+        let Some(report) = &day.report else {
+            return Ok(());
+        };
+
+        self.answers.record(report);
+        self.answers.save(&self.config.answers)?;
+
+        day.verdicts = self.answers.verify(report);
+        day.status = Status::of(report, &day.verdicts);
+
+        return Ok(());
+    }
+
+    /// The diff of every part of the day at `i` against the stored answer.
+    pub fn diffs(self: &Self, i: usize) -> Vec<(u32, Vec<Line>)> {
+        let day = &self.days[i];
+        let Some(report) = &day.report else {
+            return Vec::new();
+        };
+
+        return (1..)
+            .zip(&report.parts)
+            .map(|(part, stage)| {
+                let expected = self
+                    .answers
+                    .get(day.entry.year, day.entry.day, part)
+                    .unwrap_or("");
+                let actual = match stage.status {
+                    Stage::Solved => stage.answer.as_deref().unwrap_or(""),
+                    _ => "",
+                };
+                (part, diff(expected, actual))
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Examples, Line, Status};
+    use crate::answers::Verdict;
+    use crate::runner::{Report, Stage, Status as StageStatus};
+    use std::time::Duration;
+
+    fn report(status: StageStatus) -> Report {
+        let stage = Stage {
+            status,
+            answer: Some("1".to_string()),
+            time: Duration::ZERO,
+            memory: None,
+        };
+
+        Report {
+            year: 2022,
+            day: 1,
+            parse: stage.clone(),
+            parts: vec![stage.clone(), stage],
+            render: None,
+            cached: false,
+        }
+    }
+
+    #[test]
+    fn status_compares_with_the_answers() {
+        let solved = report(StageStatus::Solved);
+        let wrong = Verdict::Wrong {
+            expected: "2".to_string(),
+        };
+
+        assert_eq!(
+            Status::of(&solved, &[Verdict::Correct, Verdict::Correct]),
+            Status::Verified
+        );
+        assert_eq!(
+            Status::of(&solved, &[Verdict::Correct, Verdict::Unknown]),
+            Status::Solved
+        );
+        assert_eq!(
+            Status::of(&solved, &[Verdict::Correct, wrong]),
+            Status::Failing
+        );
+        assert_eq!(
+            Status::of(&report(StageStatus::TimedOut), &[]),
+            Status::Failing
+        );
+    }
+
+    #[test]
+    fn examples_sum_the_test_results() {
+        let output = "running 2 tests
+test y2022::day01::tests::part1_example1 ... ok
+test y2022::day01::tests::part2_example1 ... FAILED
+
+test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; 85 filtered out; finished in 0.00s
+";
+        let examples = Examples::parse(output).unwrap();
+
+        assert_eq!((examples.passed, examples.failed), (1, 1));
+        assert_eq!(examples.label(), "1/2 failed");
+        assert_eq!(Examples::parse("error: could not compile"), None);
+    }
+
+    #[test]
+    fn diff_keeps_the_common_lines() {
+        assert_eq!(
+            super::diff("a\nb\nc", "a\nx\nc"),
+            vec![
+                Line::Same("a".to_string()),
+                Line::Added("x".to_string()),
+                Line::Removed("b".to_string()),
+                Line::Same("c".to_string()),
+            ]
+        );
+        assert_eq!(super::diff("", "1"), vec![Line::Added("1".to_string())]);
+    }
+}
@@ -0,0 +1,344 @@
+//! The terminal side of the dashboard: a table of the days, with views of the
+//! output of a day, its diff against the answer store and its example tests.
+
+use std::time::Duration;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line as Text, Span};
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+use super::{Dashboard, Day, Line, Status};
+use crate::config::{day_key, Args};
+use crate::error::Error;
+use crate::runner::Status as Stage;
+
+const HELP: &str =
+    "↑↓ select  r run  a run all  t test  o output  d diff  e examples  s accept  esc back  q quit";
+
+/// What the lower pane shows for the selected day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum View {
+    Summary,
+    Output,
+    Diff,
+    Examples,
+}
+
+struct App {
+    dashboard: Dashboard,
+    table: TableState,
+    view: View,
+    scroll: u16,
+    /// The outcome of the last action that has no place in the table.
+    notice: String,
+}
+
+fn color(status: Status) -> Color {
+    return match status {
+        Status::NotRun | Status::NoInput => Color::DarkGray,
+        Status::Running => Color::Yellow,
+        Status::Solved => Color::Cyan,
+        Status::Verified => Color::Green,
+        Status::Failing => Color::Red,
+    };
+}
+
+/// The answer of a part for the table: its first line only, as multi-line
+/// answers such as the letters of 2022/day10 only fit in the output view.
+fn answer(day: &Day, part: usize) -> String {
+    let Some(stage) = day
+        .report
+        .as_ref()
+        .and_then(|report| report.parts.get(part))
+    else {
+        return String::new();
+    };
+
+    return match (&stage.answer, stage.status) {
+        (Some(answer), _) if answer.contains('\n') => "(multi-line)".to_string(),
+        (Some(answer), _) => answer.clone(),
+        (None, Stage::Failed) => "failed".to_string(),
+        (None, Stage::TimedOut) => "timed out".to_string(),
+        (None, _) => String::new(),
+    };
+}
+
+fn time(day: &Day) -> String {
+    let Some(report) = &day.report else {
+        return String::new();
+    };
+
+    let total = std::iter::once(&report.parse)
+        .chain(&report.parts)
+        .map(|stage| stage.time)
+        .sum::<Duration>();
+    let time = format!("{:.1} ms", total.as_secs_f64() * 1000.0);
+
+    return match report.cached {
+        true => time + " (cached)",
+        false => time,
+    };
+}
+
+impl App {
+    fn selected(self: &Self) -> usize {
+        return self.table.selected().unwrap_or(0);
+    }
+
+    fn select(self: &mut Self, i: usize) {
+        self.table.select(Some(i));
+        self.scroll = 0;
+    }
+
+    /// Handles a key; returns whether to quit.
+    fn key(self: &mut Self, code: KeyCode) -> bool {
+        let i = self.selected();
+        let last = self.dashboard.days.len().saturating_sub(1);
+
+        match code {
+            KeyCode::Char('q') => return true,
+            KeyCode::Esc => self.view = View::Summary,
+            KeyCode::Down | KeyCode::Char('j') if self.view == View::Summary => {
+                self.select((i + 1).min(last))
+            }
+            KeyCode::Up | KeyCode::Char('k') if self.view == View::Summary => {
+                self.select(i.saturating_sub(1))
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::Char('r') => self.dashboard.run(i),
+            KeyCode::Char('a') => {
+                for i in 0..self.dashboard.days.len() {
+                    if self.dashboard.days[i].status != Status::NoInput {
+                        self.dashboard.run(i);
+                    }
+                }
+            }
+            KeyCode::Char('t') => self.dashboard.test(i),
+            KeyCode::Enter | KeyCode::Char('o') => self.show(View::Output),
+            KeyCode::Char('d') => self.show(View::Diff),
+            KeyCode::Char('e') => self.show(View::Examples),
+            KeyCode::Char('s') => {
+                self.notice = match self.dashboard.accept(i) {
+                    Ok(()) => format!(
+                        "stored the answers of day {}",
+                        self.dashboard.days[i].entry.day
+                    ),
+                    Err(e) => e.to_string(),
+                }
+            }
+            _ => (),
+        }
+
+        return false;
+    }
+
+    fn show(self: &mut Self, view: View) {
+        self.view = view;
+        self.scroll = 0;
+    }
+
+    fn draw(self: &mut Self, frame: &mut Frame) {
+        let [top, bottom, help] = Layout::vertical([
+            Constraint::Min(8),
+            Constraint::Percentage(40),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        let rows = self.dashboard.days.iter().map(|day| {
+            let examples = match (&day.examples, day.testing) {
+                (_, true) => "testing".to_string(),
+                (Some(examples), false) => examples.label(),
+                (None, false) => String::new(),
+            };
+            Row::new(vec![
+                Cell::from(day_key(day.entry.day)),
+                Cell::from(day.status.label()).style(Style::new().fg(color(day.status))),
+                Cell::from(answer(day, 0)),
+                Cell::from(answer(day, 1)),
+                Cell::from(time(day)),
+                Cell::from(examples),
+            ])
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(6),
+                Constraint::Length(9),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Length(20),
+                Constraint::Length(12),
+            ],
+        )
+        .header(Row::new(["Day", "Status", "Part 1", "Part 2", "Time", "Examples"]).bold())
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .block(Block::bordered().title(format!(" Advent of Code {} ", self.dashboard.config.year)));
+        frame.render_stateful_widget(table, top, &mut self.table);
+
+        let (title, text) = self.pane();
+        let pane = Paragraph::new(text)
+            .block(Block::bordered().title(title))
+            .wrap(Wrap { trim: false })
+            .scroll((self.scroll, 0));
+        frame.render_widget(pane, bottom);
+
+        let footer = match self.notice.is_empty() {
+            true => HELP.to_string(),
+            false => format!("{}  |  {}", self.notice, HELP),
+        };
+        frame.render_widget(Paragraph::new(footer).dark_gray(), help);
+    }
+
+    /// The title and the text of the lower pane.
+    fn pane(self: &Self) -> (String, Vec<Text<'static>>) {
+        let i = self.selected();
+        let day = &self.dashboard.days[i];
+        let name = format!("{}/{}", day.entry.year, day_key(day.entry.day));
+
+        return match self.view {
+            View::Summary => (format!(" {} ", name), summary(day)),
+            View::Output => (format!(" {}: output ", name), output(day)),
+            View::Diff => {
+                let mut text = Vec::new();
+                for (part, lines) in self.dashboard.diffs(i) {
+                    text.push(Text::from(format!("Part {}:", part)).bold());
+                    text.extend(lines.into_iter().map(|line| match line {
+                        Line::Same(s) => Text::from(format!("  {}", s)),
+                        Line::Removed(s) => Text::from(format!("- {}", s)).red(),
+                        Line::Added(s) => Text::from(format!("+ {}", s)).green(),
+                    }));
+                }
+                if text.is_empty() {
+                    text.push(Text::from("not run yet"));
+                }
+                (format!(" {}: stored (-) against last run (+) ", name), text)
+            }
+            View::Examples => {
+                let text = match &day.examples {
+                    Some(examples) => examples
+                        .output
+                        .lines()
+                        .map(|l| Text::from(l.to_string()))
+                        .collect(),
+                    None => vec![Text::from("press t to run the example tests")],
+                };
+                (format!(" {}: examples ", name), text)
+            }
+        };
+    }
+}
+
+fn summary(day: &Day) -> Vec<Text<'static>> {
+    let Some(report) = &day.report else {
+        return vec![Text::from(match day.status {
+            Status::NoInput => "no input; fetch it with `aoc fetch`",
+            Status::Running => "running...",
+            _ => "not run yet; press r to run",
+        })];
+    };
+
+    let mut text = Vec::new();
+    let stages = std::iter::once(("parse".to_string(), &report.parse)).chain(
+        (1..)
+            .zip(&report.parts)
+            .map(|(part, stage)| (format!("part {}", part), stage)),
+    );
+    for (name, stage) in stages {
+        let status = format!("{:?}", stage.status).to_lowercase();
+        text.push(Text::from(format!(
+            "{:<8}{:<10}{:>10.3} ms",
+            name,
+            status,
+            stage.time.as_secs_f64() * 1000.0
+        )));
+    }
+
+    for (part, verdict) in (1..).zip(&day.verdicts) {
+        let line = match verdict {
+            crate::answers::Verdict::Unknown => {
+                Span::from(format!("part {}: no stored answer", part)).cyan()
+            }
+            crate::answers::Verdict::Correct => {
+                Span::from(format!("part {}: matches the stored answer", part)).green()
+            }
+            crate::answers::Verdict::Wrong { expected } => {
+                Span::from(format!("part {}: should be {}", part, expected)).red()
+            }
+        };
+        text.push(Text::from(line));
+    }
+
+    return text;
+}
+
+fn output(day: &Day) -> Vec<Text<'static>> {
+    let mut text = Vec::new();
+
+    if let Some(report) = &day.report {
+        for (part, stage) in (1..).zip(&report.parts) {
+            text.push(Text::from(format!("Part {}:", part)).bold());
+            let answer = stage.answer.as_deref().unwrap_or("");
+            text.extend(answer.lines().map(|line| Text::from(line.to_string())));
+        }
+    }
+
+    if !day.messages.trim().is_empty() {
+        text.push(Text::from("stderr:").bold());
+        text.extend(
+            day.messages
+                .lines()
+                .map(|line| Text::from(line.to_string())),
+        );
+    }
+
+    if text.is_empty() {
+        text.push(Text::from("not run yet"));
+    }
+
+    return text;
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> Result<(), Error> {
+    loop {
+        app.dashboard.update();
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if !event::poll(Duration::from_millis(100))? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && app.key(key.code) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Opens the dashboard of the year of `args` until the user quits.
+pub fn main(args: Args) -> Result<(), Error> {
+    let dashboard = Dashboard::new(args)?;
+    let mut app = App {
+        dashboard,
+        table: TableState::default().with_selected(Some(0)),
+        view: View::Summary,
+        scroll: 0,
+        notice: String::new(),
+    };
+    if app.dashboard.days.is_empty() {
+        return Err(Error::ConfigError(format!(
+            "there are no days in {}",
+            app.dashboard.config.year
+        )));
+    }
+
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+
+    return result;
+}
@@ -0,0 +1,300 @@
+//! Memoization and dynamic programming tables for state-space puzzles.
+
+use std::collections::hash_map::{Entry, HashMap};
+use std::hash::Hash;
+
+use crate::collections::{BitSet, BitSet64};
+
+/// The values of a recursive function, keyed by its state.
+///
+/// ```
+/// use aoc::dp::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| match n {
+///         0 | 1 => n,
+///         _ => fib(memo, n - 1) + fib(memo, n - 2),
+///     })
+/// }
+///
+/// assert_eq!(fib(&mut Memo::new(), 90), 2880067194370816120);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+}
+
+impl<K: Eq + Hash, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        return Memo {
+            values: HashMap::new(),
+        };
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        return Memo::default();
+    }
+
+    /// The value of `key`, computed by `f` the first time only. `f` gets the
+    /// memo back, to recurse into other states.
+    pub fn get_or_compute(self: &mut Self, key: K, f: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.values.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(self);
+        self.values.insert(key, value.clone());
+
+        return value;
+    }
+
+    pub fn get(self: &Self, key: &K) -> Option<&V> {
+        return self.values.get(key);
+    }
+
+    /// Stores the value of `key`, for values that depend on more than the key
+    /// and must sometimes be recomputed.
+    pub fn insert(self: &mut Self, key: K, value: V) {
+        self.values.insert(key, value);
+    }
+
+    /// The number of states computed so far.
+    pub fn len(self: &Self) -> usize {
+        return self.values.len();
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        return self.values.is_empty();
+    }
+}
+
+/// The index of a [`Table`]: a time, a node and a set of nodes.
+pub type State = (usize, usize, BitSet64);
+
+/// A dynamic programming table over [`State`]s that keeps the best value
+/// seen for each state. States never updated have no value, so there is no
+/// sentinel to guard against.
+pub trait Table<V: Copy + Ord> {
+    fn get(self: &Self, state: State) -> Option<V>;
+
+    /// Sets the value of `state` to `value` if it is better than the current
+    /// one, returning whether it was.
+    fn update(self: &mut Self, state: State, value: V) -> bool;
+
+    /// The states of time `time` that have a value, with that value.
+    fn layer(self: &Self, time: usize) -> Box<dyn Iterator<Item = (usize, BitSet64, V)> + '_>;
+
+    /// The best value of every set at time `time`, whatever the node.
+    fn best_by_set(self: &Self, time: usize) -> HashMap<BitSet64, V> {
+        let mut best = HashMap::new();
+        for (_, set, value) in self.layer(time) {
+            best.entry(set)
+                .and_modify(|v: &mut V| *v = (*v).max(value))
+                .or_insert(value);
+        }
+
+        return best;
+    }
+}
+
+/// A [`Table`] holding every state of `times × nodes × 2^nodes`, for small
+/// numbers of nodes where most states are reachable.
+#[derive(Debug, Clone)]
+pub struct DenseTable<V> {
+    values: Vec<V>,
+    set: BitSet,
+    nodes: usize,
+}
+
+impl<V: Copy + Default> DenseTable<V> {
+    /// A table for the times `0..times` and the nodes `0..nodes`. Panics when
+    /// there are more than [`BitSet64::CAPACITY`] nodes.
+    pub fn new(times: usize, nodes: usize) -> DenseTable<V> {
+        assert!(nodes <= BitSet64::CAPACITY, "too many nodes for a mask");
+
+        let len = times
+            .checked_mul(nodes)
+            .and_then(|n| n.checked_mul(1usize.checked_shl(nodes as u32)?))
+            .expect("the table to fit in memory");
+
+        return DenseTable {
+            values: vec![V::default(); len],
+            set: BitSet::with_capacity(len),
+            nodes,
+        };
+    }
+
+    pub fn times(self: &Self) -> usize {
+        return match self.nodes {
+            0 => 0,
+            _ => self.values.len() / (self.nodes << self.nodes),
+        };
+    }
+
+    pub fn nodes(self: &Self) -> usize {
+        return self.nodes;
+    }
+
+    fn index(self: &Self, (time, node, set): State) -> Option<usize> {
+        if time >= self.times() || node >= self.nodes || set.bits() >> self.nodes != 0 {
+            return None;
+        }
+
+        return Some(((time * self.nodes + node) << self.nodes) + set.bits() as usize);
+    }
+}
+
+impl<V: Copy + Default + Ord> Table<V> for DenseTable<V> {
+    fn get(self: &Self, state: State) -> Option<V> {
+        let i = self.index(state)?;
+        return self.set.contains(i).then(|| self.values[i]);
+    }
+
+    /// Out of range states are ignored.
+    fn update(self: &mut Self, state: State, value: V) -> bool {
+        let Some(i) = self.index(state) else {
+            return false;
+        };
+
+        if self.set.contains(i) && self.values[i] >= value {
+            return false;
+        }
+
+        self.set.insert(i);
+        self.values[i] = value;
+
+        return true;
+    }
+
+    fn layer(self: &Self, time: usize) -> Box<dyn Iterator<Item = (usize, BitSet64, V)> + '_> {
+        if time >= self.times() {
+            return Box::new(std::iter::empty());
+        }
+
+        let per_node = 1 << self.nodes;
+        let start = time * self.nodes * per_node;
+
+        return Box::new(
+            (start..start + self.nodes * per_node)
+                .filter(|&i| self.set.contains(i))
+                .map(move |i| {
+                    let node = (i - start) / per_node;
+                    let set = BitSet64::from_bits(((i - start) % per_node) as u64);
+                    (node, set, self.values[i])
+                }),
+        );
+    }
+}
+
+/// A [`Table`] holding only the states that were updated, for when few of
+/// them are reachable.
+#[derive(Debug, Clone, Default)]
+pub struct SparseTable<V> {
+    layers: Vec<HashMap<(usize, BitSet64), V>>,
+}
+
+impl<V> SparseTable<V> {
+    pub fn new() -> SparseTable<V> {
+        return SparseTable { layers: Vec::new() };
+    }
+
+    /// The number of states with a value.
+    pub fn len(self: &Self) -> usize {
+        return self.layers.iter().map(HashMap::len).sum();
+    }
+
+    pub fn is_empty(self: &Self) -> bool {
+        return self.layers.iter().all(HashMap::is_empty);
+    }
+}
+
+impl<V: Copy + Ord> Table<V> for SparseTable<V> {
+    fn get(self: &Self, (time, node, set): State) -> Option<V> {
+        return self.layers.get(time)?.get(&(node, set)).copied();
+    }
+
+    fn update(self: &mut Self, (time, node, set): State, value: V) -> bool {
+        if time >= self.layers.len() {
+            self.layers.resize_with(time + 1, HashMap::new);
+        }
+
+        match self.layers[time].entry((node, set)) {
+            Entry::Occupied(mut current) => {
+                if *current.get() >= value {
+                    return false;
+                }
+                current.insert(value);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+            }
+        }
+
+        return true;
+    }
+
+    fn layer(self: &Self, time: usize) -> Box<dyn Iterator<Item = (usize, BitSet64, V)> + '_> {
+        return match self.layers.get(time) {
+            Some(layer) => Box::new(
+                layer
+                    .iter()
+                    .map(|(&(node, set), &value)| (node, set, value)),
+            ),
+            None => Box::new(std::iter::empty()),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DenseTable, Memo, SparseTable, Table};
+    use crate::collections::BitSet64;
+
+    #[test]
+    fn memo_computes_each_state_once() {
+        fn paths(memo: &mut Memo<(u32, u32), u64>, calls: &mut u32, (x, y): (u32, u32)) -> u64 {
+            *calls += 1;
+            memo.get_or_compute((x, y), |memo| match (x, y) {
+                (0, _) | (_, 0) => 1,
+                _ => paths(memo, calls, (x - 1, y)) + paths(memo, calls, (x, y - 1)),
+            })
+        }
+
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        assert_eq!(paths(&mut memo, &mut calls, (16, 16)), 601080390);
+        assert_eq!(memo.len(), 17 * 17 - 1);
+        assert!(calls < 2 * 17 * 17);
+    }
+
+    fn max_update(table: &mut impl Table<i64>) {
+        let set = BitSet64::new().with(1);
+
+        assert_eq!(table.get((2, 1, set)), None);
+        assert!(table.update((2, 1, set), 5));
+        assert!(!table.update((2, 1, set), 3));
+        assert!(table.update((2, 1, set), 8));
+        assert!(table.update((2, 0, BitSet64::new()), 9));
+        assert_eq!(table.get((2, 1, set)), Some(8));
+
+        let mut layer = table.layer(2).collect::<Vec<_>>();
+        layer.sort();
+        assert_eq!(layer, vec![(0, BitSet64::new(), 9), (1, set, 8)]);
+        assert_eq!(table.layer(1).count(), 0);
+        assert_eq!(table.best_by_set(2)[&set], 8);
+    }
+
+    #[test]
+    fn tables_keep_the_best_value() {
+        let mut dense = DenseTable::new(3, 2);
+        max_update(&mut dense);
+        assert!(!dense.update((3, 0, BitSet64::new()), 1));
+        assert!(!dense.update((0, 0, BitSet64::new().with(2)), 1));
+
+        let mut sparse = SparseTable::new();
+        max_update(&mut sparse);
+        assert_eq!(sparse.len(), 2);
+    }
+}
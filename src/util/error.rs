@@ -1,4 +1,30 @@
+use std::fmt;
+
 #[derive(Debug)]
 pub enum Error {
     ParseError(String),
+    ConfigError(String),
+    IoError(String),
+    HttpError(String),
+    SubmitError(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ParseError(message) => write!(f, "parse error: {}", message),
+            Error::ConfigError(message) => write!(f, "config error: {}", message),
+            Error::IoError(message) => write!(f, "io error: {}", message),
+            Error::HttpError(message) => write!(f, "http error: {}", message),
+            Error::SubmitError(message) => write!(f, "not submitted: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        return Error::IoError(error.to_string());
+    }
 }
@@ -0,0 +1,32 @@
+/// The orthogonal neighbors of `(row, col)` that fall inside a grid of
+/// `height` rows and `width` columns.
+pub fn neighbors4(
+    (row, col): (usize, usize),
+    height: usize,
+    width: usize,
+) -> impl Iterator<Item = (usize, usize)> {
+    [(0, 1), (0, -1), (-1, 0), (1, 0)]
+        .into_iter()
+        .filter_map(move |(i, j)| {
+            let row = row.checked_add_signed(i)?;
+            let col = col.checked_add_signed(j)?;
+
+            if row >= height || col >= width {
+                return None;
+            }
+
+            return Some((row, col));
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn neighbors4_stays_inside() {
+        let corner = super::neighbors4((0, 0), 3, 2).collect::<Vec<_>>();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        let middle = super::neighbors4((1, 1), 3, 3).count();
+        assert_eq!(middle, 4);
+    }
+}
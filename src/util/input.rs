@@ -1,4 +1,8 @@
-use std::io::Read;
+use std::io::{BufRead, BufReader, IsTerminal, Read};
+
+use crate::client::Client;
+use crate::config::Config;
+use crate::error::Error;
 
 pub fn read_from_stdin() -> String {
     load_text_input(std::io::stdin().lock())
@@ -9,3 +13,107 @@ pub fn load_text_input<R: Read>(mut input: R) -> String {
     input.read_to_string(&mut buffer).unwrap();
     return buffer;
 }
+
+/// Reads the input of `day` of `year`: the `--input` file when one was given,
+/// then stdin when it is redirected and not empty, and finally the file in the
+/// input directory.
+pub fn read_input(config: &Config, year: u32, day: u32) -> Result<String, Error> {
+    if let Some(path) = &config.input {
+        return read_file(path);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        let input = read_from_stdin();
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+
+    return read_file(config.input_path(year, day));
+}
+
+/// Opens the input of `day` of `year` for reading it bit by bit, from the same
+/// places as [`read_input`]. Only the first bytes of stdin are read to find
+/// whether it is empty.
+pub fn open_input(config: &Config, year: u32, day: u32) -> Result<Box<dyn Read>, Error> {
+    if config.input.is_none() && !std::io::stdin().is_terminal() {
+        let mut stdin = BufReader::new(std::io::stdin());
+        if !stdin.fill_buf()?.is_empty() {
+            return Ok(Box::new(stdin));
+        }
+    }
+
+    let path = match &config.input {
+        Some(path) => path.clone(),
+        None => config.input_path(year, day),
+    };
+
+    let file = std::fs::File::open(&path)
+        .map_err(|e| Error::IoError(format!("failed to read {}: {}", path.display(), e)))?;
+
+    return Ok(Box::new(file));
+}
+
+pub fn read_file(path: impl AsRef<std::path::Path>) -> Result<String, Error> {
+    let path = path.as_ref();
+
+    return std::fs::read_to_string(path)
+        .map_err(|e| Error::IoError(format!("failed to read {}: {}", path.display(), e)));
+}
+
+/// Downloads the input of `day` of `year` to the input directory, unless it is
+/// already there or `force` is set. Returns whether it was downloaded.
+pub fn fetch_input(
+    client: &Client,
+    config: &Config,
+    year: u32,
+    day: u32,
+    force: bool,
+) -> Result<bool, Error> {
+    let path = config.input_path(year, day);
+    if path.exists() && !force {
+        return Ok(false);
+    }
+
+    let input = client.fetch_input(year, day)?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, input)?;
+
+    return Ok(true);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::{stub, Client};
+    use crate::config::Config;
+    use std::time::Duration;
+
+    #[test]
+    fn fetch_input_caches_the_download() {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let mut config = Config::default();
+        config.input_dir = dir.clone();
+
+        let (url, server) = stub::serve(vec![(200, "1\n"), (200, "2\n")]);
+        let client = Client::new(url, "abc", Duration::ZERO);
+
+        assert!(super::fetch_input(&client, &config, 2022, 1, false).unwrap());
+        assert!(!super::fetch_input(&client, &config, 2022, 1, false).unwrap());
+        assert_eq!(
+            super::read_file(dir.join("2022/day01.input")).unwrap(),
+            "1\n"
+        );
+
+        assert!(super::fetch_input(&client, &config, 2022, 1, true).unwrap());
+        assert_eq!(
+            super::read_file(dir.join("2022/day01.input")).unwrap(),
+            "2\n"
+        );
+
+        server.join().unwrap();
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
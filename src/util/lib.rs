@@ -1,4 +1,23 @@
+pub mod answers;
+pub mod cache;
+pub mod client;
 pub mod collections;
+pub mod config;
+#[cfg(feature = "tui")]
+pub mod dashboard;
+pub mod dp;
 pub mod error;
+pub mod grid;
 pub mod input;
+pub mod log;
+pub mod math;
+pub mod memory;
 pub mod parsing;
+pub mod reference;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
+pub mod y2022;
@@ -0,0 +1,245 @@
+//! The debug channel of the solutions: messages and dumps with a [`Level`],
+//! written to stderr or to a file, never to stdout where the answers go.
+//!
+//! The binaries set it up with [`init`] from the `-v`, `-q` and `--log`
+//! options; until then only warnings and errors are written, to stderr.
+//!
+//! ```
+//! let tower = "#######";
+//! aoc::debug!("the tower:\n{}", tower);
+//! ```
+//!
+//! The arguments are only formatted when the level is enabled, so large dumps
+//! cost nothing in normal runs.
+
+use std::fmt;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+use serde::Deserialize;
+
+use crate::error::Error;
+
+/// How much is written, from only the errors to everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    #[default]
+    Warn,
+    Info,
+    /// Dumps of the state of a puzzle, such as the tower of 2022/day17.
+    Debug,
+    Trace,
+}
+
+impl Level {
+    const ALL: [Level; 5] = [
+        Level::Error,
+        Level::Warn,
+        Level::Info,
+        Level::Debug,
+        Level::Trace,
+    ];
+
+    /// The level of `-v` given `verbose` times and `-q` given `quiet` times,
+    /// starting from `base`.
+    pub fn adjust(base: Level, verbose: u8, quiet: u8) -> Level {
+        let level = (base as i32 + verbose as i32 - quiet as i32).clamp(0, 4);
+        return Level::ALL[level as usize];
+    }
+
+    pub fn name(self: &Self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Level {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return Level::ALL
+            .into_iter()
+            .find(|level| level.name() == s)
+            .ok_or_else(|| Error::ConfigError(format!("unknown log level {}", s)));
+    }
+}
+
+/// A level and where the messages up to it go. The macros write through the
+/// one of the process, which the functions of this module set up.
+#[derive(Debug)]
+pub struct Logger {
+    level: AtomicU8,
+    /// The log file; stderr when there is none.
+    file: Mutex<Option<File>>,
+}
+
+impl Logger {
+    /// Writes only the warnings and errors, to stderr.
+    pub const fn new() -> Logger {
+        return Logger {
+            level: AtomicU8::new(Level::Warn as u8),
+            file: Mutex::new(None),
+        };
+    }
+
+    /// Writes the messages up to `level`, to the file at `path` (created
+    /// anew) or to stderr.
+    pub fn init(self: &Self, level: Level, path: Option<&Path>) -> Result<(), Error> {
+        let file = match path {
+            Some(path) => Some(File::create(path).map_err(|e| {
+                Error::IoError(format!("failed to create {}: {}", path.display(), e))
+            })?),
+            None => None,
+        };
+
+        *self.file.lock().unwrap_or_else(|e| e.into_inner()) = file;
+        self.level.store(level as u8, Ordering::Relaxed);
+
+        return Ok(());
+    }
+
+    pub fn level(self: &Self) -> Level {
+        return Level::ALL[self.level.load(Ordering::Relaxed) as usize];
+    }
+
+    pub fn enabled(self: &Self, level: Level) -> bool {
+        return level as u8 <= self.level.load(Ordering::Relaxed);
+    }
+
+    /// Writes a message at `level`, when it is enabled.
+    pub fn write(self: &Self, level: Level, message: fmt::Arguments) {
+        if !self.enabled(level) {
+            return;
+        }
+
+        let text = format!("[{}] {}\n", level, message);
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        // The channel is best effort: a full disk must not fail a solution.
+        let _ = match file.as_mut() {
+            Some(file) => file.write_all(text.as_bytes()),
+            None => std::io::stderr().lock().write_all(text.as_bytes()),
+        };
+    }
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        return Logger::new();
+    }
+}
+
+static LOGGER: Logger = Logger::new();
+
+/// See [`Logger::init`].
+pub fn init(level: Level, path: Option<&Path>) -> Result<(), Error> {
+    return LOGGER.init(level, path);
+}
+
+pub fn level() -> Level {
+    return LOGGER.level();
+}
+
+pub fn enabled(level: Level) -> bool {
+    return LOGGER.enabled(level);
+}
+
+/// Writes a message at `level`; see the macros, which skip formatting it when
+/// the level is disabled.
+pub fn write(level: Level, message: fmt::Arguments) {
+    LOGGER.write(level, message);
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Level, Logger};
+
+    #[test]
+    fn adjust_moves_within_the_levels() {
+        assert_eq!(Level::adjust(Level::Warn, 0, 0), Level::Warn);
+        assert_eq!(Level::adjust(Level::Warn, 2, 0), Level::Debug);
+        assert_eq!(Level::adjust(Level::Warn, 9, 0), Level::Trace);
+        assert_eq!(Level::adjust(Level::Warn, 0, 3), Level::Error);
+        assert_eq!(Level::adjust(Level::Info, 1, 1), Level::Info);
+        assert_eq!("debug".parse::<Level>().unwrap(), Level::Debug);
+        assert!("loud".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn messages_go_to_the_file() {
+        let path = std::env::temp_dir().join(format!("aoc-log-{}.log", std::process::id()));
+        let logger = Logger::new();
+        logger.init(Level::Debug, Some(&path)).unwrap();
+
+        logger.write(Level::Debug, format_args!("tower:\n{}", "#.#\n###"));
+        logger.write(Level::Trace, format_args!("hidden"));
+        logger.init(Level::Warn, None).unwrap();
+        logger.write(Level::Warn, format_args!("to stderr"));
+
+        let log = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(log, "[debug] tower:\n#.#\n###\n");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn disabled_messages_are_not_formatted() {
+        // No test changes the level of the process from its default.
+        let mut formatted = false;
+        crate::trace!("{}", {
+            formatted = true;
+            ""
+        });
+
+        assert!(!formatted);
+    }
+}
@@ -0,0 +1,339 @@
+//! Number theory shared by the puzzles of every event.
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+/// The primitive integers the helpers of this module work on.
+pub trait Int:
+    Copy
+    + Ord
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The absolute value, `None` for the `MIN` of a signed integer; the
+    /// identity for unsigned integers.
+    fn checked_abs(self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! int {
+    ($signed:literal => $($t:ty)*) => {$(
+        impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            #[allow(unused_comparisons)]
+            fn checked_abs(self) -> Option<Self> {
+                match $signed && self < 0 {
+                    true => <$t>::checked_neg(self),
+                    false => Some(self),
+                }
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+        }
+    )*};
+}
+
+int!(false => u8 u16 u32 u64 u128 usize);
+int!(true => i8 i16 i32 i64 i128 isize);
+
+/// The greatest common divisor, never negative; `gcd(0, 0)` is 0. `None`
+/// when it overflows `T`, which only `gcd(MIN, 0)` and `gcd(MIN, MIN)` do.
+pub fn gcd<T: Int>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = match (a.checked_abs(), b.checked_abs()) {
+        (Some(a), Some(b)) => (a, b),
+        // The absolute value of `MIN` does not fit, but its remainder does.
+        (None, Some(n)) | (Some(n), None) if n != T::ZERO => (n, (a.min(b) % n).checked_abs()?),
+        _ => return None,
+    };
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+
+    return Some(a);
+}
+
+/// The least common multiple, never negative; `None` when it overflows `T`.
+pub fn lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    return (a.checked_abs()? / gcd(a, b)?).checked_mul(b.checked_abs()?);
+}
+
+/// The least common multiple of every number, 1 for none.
+pub fn lcm_all<T: Int>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers.into_iter().try_fold(T::ONE, |acc, n| lcm(acc, n))
+}
+
+/// `a / b` when `b` divides `a`, and `None` otherwise.
+pub fn exact_div<T: Int>(a: T, b: T) -> Option<T> {
+    if b == T::ZERO || a % b != T::ZERO {
+        return None;
+    }
+
+    return Some(a / b);
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        return (-r0, -x0, -y0);
+    }
+
+    return (r0, x0, y0);
+}
+
+/// The inverse of `a` modulo `m`, when they are coprime and `m` is positive.
+pub fn mod_inverse(a: i128, m: i128) -> Option<i128> {
+    if m <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+
+    return Some(x.rem_euclid(m));
+}
+
+/// Solves `x = r (mod m)` for every `(r, m)` of `congruences`, returning
+/// `(x, lcm of the moduli)` with `0 <= x < lcm`. The moduli need not be
+/// coprime; `None` when a modulus is not positive, when the congruences
+/// contradict each other or when the lcm overflows.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    let mut result = (0, 1);
+
+    for &(r, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+
+        let (x, n) = result;
+        let (g, p, _) = extended_gcd(n, m);
+        if (r - x) % g != 0 {
+            return None;
+        }
+
+        let lcm = (n / g).checked_mul(m)?;
+        let step = ((r - x) / g).checked_mul(p)?.rem_euclid(m / g);
+        result = ((x + n.checked_mul(step)?).rem_euclid(lcm), lcm);
+    }
+
+    return Some(result);
+}
+
+/// An integer modulo `modulus`.
+///
+/// Mixing integers of different moduli panics, and division is only defined
+/// when the divisor is invertible, see [`ModInt::checked_div`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    /// `value` reduced modulo `modulus`, which must be at least 1.
+    pub fn new(value: i128, modulus: u64) -> ModInt {
+        assert!(modulus > 0, "the modulus must be positive");
+
+        return ModInt {
+            value: value.rem_euclid(modulus as i128) as u64,
+            modulus,
+        };
+    }
+
+    pub fn value(self: &Self) -> u64 {
+        return self.value;
+    }
+
+    pub fn modulus(self: &Self) -> u64 {
+        return self.modulus;
+    }
+
+    fn with(self: &Self, value: u128) -> ModInt {
+        return ModInt {
+            value: (value % self.modulus as u128) as u64,
+            modulus: self.modulus,
+        };
+    }
+
+    fn check(self: &Self, other: &ModInt) {
+        assert_eq!(
+            self.modulus, other.modulus,
+            "integers of different moduli cannot be mixed"
+        );
+    }
+
+    pub fn pow(self: Self, exponent: u64) -> ModInt {
+        let mut result = self.with(1);
+        let mut base = self;
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result * base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+
+        return result;
+    }
+
+    /// The inverse, when the value and the modulus are coprime.
+    pub fn inverse(self: &Self) -> Option<ModInt> {
+        let inverse = mod_inverse(self.value as i128, self.modulus as i128)?;
+        return Some(self.with(inverse as u128));
+    }
+
+    pub fn checked_div(self: Self, other: ModInt) -> Option<ModInt> {
+        self.check(&other);
+        return Some(self * other.inverse()?);
+    }
+}
+
+impl fmt::Display for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl Add for ModInt {
+    type Output = ModInt;
+
+    fn add(self, other: ModInt) -> ModInt {
+        self.check(&other);
+        self.with(self.value as u128 + other.value as u128)
+    }
+}
+
+impl Sub for ModInt {
+    type Output = ModInt;
+
+    fn sub(self, other: ModInt) -> ModInt {
+        self.check(&other);
+        self.with(self.value as u128 + self.modulus as u128 - other.value as u128)
+    }
+}
+
+impl Mul for ModInt {
+    type Output = ModInt;
+
+    fn mul(self, other: ModInt) -> ModInt {
+        self.check(&other);
+        self.with(self.value as u128 * other.value as u128)
+    }
+}
+
+impl Neg for ModInt {
+    type Output = ModInt;
+
+    fn neg(self) -> ModInt {
+        self.with(self.modulus as u128 - self.value as u128)
+    }
+}
+
+/// Arbitrary-precision integers, for the puzzles whose numbers outgrow `i128`.
+#[cfg(feature = "bigint")]
+pub use ibig::{IBig as BigInt, UBig as BigUint};
+
+#[cfg(test)]
+mod tests {
+    use super::ModInt;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(super::gcd(12, 18), Some(6));
+        assert_eq!(super::gcd(-12i64, 18), Some(6));
+        assert_eq!(super::gcd(0u32, 0), Some(0));
+        assert_eq!(super::gcd(i8::MIN, 12), Some(4));
+        assert_eq!(super::gcd(-1, i32::MIN), Some(1));
+        assert_eq!(super::gcd(i8::MIN, 0), None);
+        assert_eq!(super::gcd(i8::MIN, i8::MIN), None);
+        assert_eq!(super::lcm(i8::MIN, 2), None);
+        assert_eq!(super::lcm(i8::MIN, 0), Some(0));
+        assert_eq!(super::lcm(4, 6), Some(12));
+        assert_eq!(super::lcm_all([2usize, 3, 5, 2, 3]), Some(30));
+        assert_eq!(super::lcm(u8::MAX, 254), None);
+        assert_eq!(super::exact_div(12, 4), Some(3));
+        assert_eq!(super::exact_div(13, 4), None);
+        assert_eq!(super::exact_div(13, 0), None);
+    }
+
+    #[test]
+    fn extended_gcd_finds_bezout_coefficients() {
+        let (g, x, y) = super::extended_gcd(240, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+
+        assert_eq!(super::mod_inverse(3, 7), Some(5));
+        assert_eq!(super::mod_inverse(2, 4), None);
+        assert_eq!(super::mod_inverse(2, 0), None);
+    }
+
+    #[test]
+    fn crt_solves_congruences() {
+        assert_eq!(super::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(super::crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(super::crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(super::crt(&[]), Some((0, 1)));
+        assert_eq!(super::crt(&[(2, 3), (0, 0)]), None);
+        assert_eq!(super::crt(&[(1, -5)]), None);
+    }
+
+    #[test]
+    fn mod_int_arithmetic() {
+        let a = ModInt::new(-3, 7);
+        let b = ModInt::new(5, 7);
+
+        assert_eq!(a.value(), 4);
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((a - b).value(), 6);
+        assert_eq!((a * b).value(), 6);
+        assert_eq!((-a).value(), 3);
+        assert_eq!(b.pow(6).value(), 1);
+        assert_eq!(a.checked_div(b).map(|c| c * b), Some(a));
+        assert_eq!(ModInt::new(2, 4).inverse(), None);
+
+        let big = ModInt::new(u64::MAX as i128 - 1, u64::MAX);
+        assert_eq!((big * big).value(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mod_int_rejects_mixed_moduli() {
+        let _ = ModInt::new(1, 7) + ModInt::new(1, 11);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn big_integers() {
+        let factorial = (1..=30u32).fold(super::BigInt::from(1), |acc, n| acc * n);
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+    }
+}
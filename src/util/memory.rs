@@ -0,0 +1,197 @@
+//! Memory profiling of the solutions, with a global allocator that counts the
+//! bytes it hands out.
+//!
+//! A binary opts in by installing [`Counting`] and calling [`enable`]:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: aoc::memory::Counting = aoc::memory::Counting;
+//! ```
+//!
+//! The counts are kept for each thread, and the runner solves every input on
+//! a thread of its own, so a solver left running after a time limit does not
+//! skew the counts of the next ones. Threads spawned by a solution are not
+//! counted with it.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    /// Signed, as memory allocated before [`enable`], or on another thread,
+    /// may be freed after it.
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+    static TOTAL: Cell<u64> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting the allocations once [`enable`]d.
+pub struct Counting;
+
+// The counters have no destructor, so they can be reached while a thread
+// exits; `try_with` only guards against the allocator being called then.
+fn grow(bytes: usize) {
+    let _ = CURRENT.try_with(|current| {
+        current.set(current.get() + bytes as isize);
+        PEAK.with(|peak| peak.set(peak.get().max(current.get())));
+        TOTAL.with(|total| total.set(total.get() + bytes as u64));
+    });
+}
+
+fn shrink(bytes: usize) {
+    let _ = CURRENT.try_with(|current| current.set(current.get() - bytes as isize));
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            grow(layout.size());
+        }
+
+        return ptr;
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            grow(layout.size());
+        }
+
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Ordering::Relaxed) {
+            shrink(layout.size());
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() && ENABLED.load(Ordering::Relaxed) {
+            match new_size > layout.size() {
+                true => grow(new_size - layout.size()),
+                false => shrink(layout.size() - new_size),
+            }
+        }
+
+        return new;
+    }
+}
+
+/// Starts counting; [`Counting`] must be the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    return ENABLED.load(Ordering::Relaxed);
+}
+
+/// The memory used by one stage of a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Usage {
+    /// The most bytes held at once, beyond those held when the stage started.
+    #[serde(rename = "peak_bytes")]
+    pub peak: u64,
+    /// Every byte allocated during the stage, including those freed since.
+    #[serde(rename = "total_bytes")]
+    pub total: u64,
+}
+
+/// Where the counters stood when a stage started.
+#[derive(Debug, Clone, Copy)]
+pub struct Mark {
+    current: isize,
+    total: u64,
+}
+
+/// Starts measuring a stage on the current thread, forgetting the peak of the
+/// previous ones.
+pub fn mark() -> Mark {
+    let current = CURRENT.with(Cell::get);
+    PEAK.with(|peak| peak.set(current));
+
+    return Mark {
+        current,
+        total: TOTAL.with(Cell::get),
+    };
+}
+
+/// The memory used on the current thread since `mark`.
+pub fn since(mark: Mark) -> Usage {
+    return Usage {
+        peak: (PEAK.with(Cell::get) - mark.current).max(0) as u64,
+        total: TOTAL.with(Cell::get) - mark.total,
+    };
+}
+
+/// `bytes` in the largest binary unit that keeps it at 1 or more.
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = ["B", "KiB", "MiB", "GiB", "TiB"];
+        let mut value = self.0 as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit + 1 < units.len() {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        match unit {
+            0 => write!(f, "{} B", self.0),
+            _ => write!(f, "{:.1} {}", value, units[unit]),
+        }
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod tests {
+    use super::Bytes;
+
+    #[test]
+    fn counts_the_allocations() {
+        super::enable();
+
+        let mark = super::mark();
+        let mut held = vec![0u8; 1 << 20];
+        held.resize(2 << 20, 1);
+        drop(vec![0u8; 1 << 20]);
+        let usage = super::since(mark);
+
+        // The growth of `held` may or may not move it.
+        assert!(
+            usage.peak >= 2 << 20 && usage.peak <= 5 << 20,
+            "{:?}",
+            usage
+        );
+        assert!(usage.total >= 3 << 20, "{:?}", usage);
+        assert_eq!(held.len(), 2 << 20);
+
+        // Other threads do not count.
+        let mark = super::mark();
+        std::thread::spawn(|| drop(vec![0u8; 8 << 20]))
+            .join()
+            .unwrap();
+        assert!(super::since(mark).total < 1 << 20);
+    }
+
+    #[test]
+    fn bytes_use_binary_units() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(Bytes(260 * 1024 * 1024).to_string(), "260.0 MiB");
+    }
+}
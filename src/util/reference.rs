@@ -0,0 +1,238 @@
+//! `aoc compare`: runs another solver of the same puzzles on the same inputs
+//! and reports where its answers differ from ours.
+//!
+//! The reference is any shell command that reads the input on stdin and
+//! prints `Part 1: ...` and `Part 2: ...` lines, as the `dayNN` binaries do.
+
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::config::day_key;
+use crate::error::Error;
+use crate::runner::Report;
+
+/// The command of `day` of `year`: `template` with `{year}`, `{day}` and
+/// `{key}` (`dayNN`) replaced.
+pub fn command(template: &str, year: u32, day: u32) -> String {
+    return template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{key}", &day_key(day));
+}
+
+/// The answers in the output of a solver, by part: the text after `Part N:`,
+/// or the lines below it up to a blank one when that is empty, as for the
+/// letters of 2022/day10.
+pub fn parse_output(output: &str) -> BTreeMap<u32, String> {
+    let mut answers = BTreeMap::new();
+    // The part whose answer is on the lines being read, and those lines.
+    let mut block: Option<(u32, Vec<&str>)> = None;
+
+    for line in output.lines() {
+        let header = line
+            .strip_prefix("Part ")
+            .and_then(|rest| rest.split_once(':'))
+            .and_then(|(part, answer)| Some((part.trim().parse::<u32>().ok()?, answer.trim())));
+
+        if let Some((part, answer)) = header {
+            if let Some((part, lines)) = block.take() {
+                answers.insert(part, lines.join("\n"));
+            }
+            if answer.is_empty() {
+                block = Some((part, Vec::new()));
+            } else {
+                answers.insert(part, answer.to_string());
+            }
+        } else if line.trim().is_empty() {
+            if let Some((part, lines)) = block.take() {
+                answers.insert(part, lines.join("\n"));
+            }
+        } else if let Some((_, lines)) = &mut block {
+            lines.push(line.trim_end());
+        }
+    }
+
+    if let Some((part, lines)) = block {
+        answers.insert(part, lines.join("\n"));
+    }
+
+    return answers;
+}
+
+/// Reads all of `pipe` on another thread.
+fn drain(mut pipe: impl Read + Send + 'static) -> std::thread::JoinHandle<Vec<u8>> {
+    return std::thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = pipe.read_to_end(&mut output);
+        output
+    });
+}
+
+/// Runs `command` with the shell, giving it `input` on stdin, and returns
+/// the answers it printed. It is killed once `timeout` runs out.
+pub fn run(
+    command: &str,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Result<BTreeMap<u32, String>, Error> {
+    let mut child = Command::new("sh")
+        .args(["-c", command])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| Error::IoError(format!("failed to run `{}`: {}", command, e)))?;
+
+    // Written from another thread, as a solver may print before it has read
+    // the whole input and block on a full pipe.
+    let mut stdin = child.stdin.take().expect("stdin to be piped");
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || {
+        // A solver that stops reading early is not an error.
+        let _ = stdin.write_all(input.as_bytes());
+    });
+
+    // Read from other threads too, as a solver that prints more than a pipe
+    // holds would block on it and never finish.
+    let stdout = drain(child.stdout.take().expect("stdout to be piped"));
+    let stderr = drain(child.stderr.take().expect("stderr to be piped"));
+
+    if let Some(timeout) = timeout {
+        let deadline = Instant::now() + timeout;
+        while child.try_wait()?.is_none() {
+            if Instant::now() >= deadline {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(Error::IoError(format!(
+                    "`{}` timed out after {}s",
+                    command,
+                    timeout.as_secs()
+                )));
+            }
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    let status = child.wait()?;
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    if !status.success() {
+        return Err(Error::IoError(format!(
+            "`{}` failed with {}: {}",
+            command,
+            status,
+            String::from_utf8_lossy(&stderr).trim()
+        )));
+    }
+
+    return Ok(parse_output(&String::from_utf8_lossy(&stdout)));
+}
+
+/// A part on which we and the reference do not agree; `None` when one side
+/// has no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub part: u32,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+}
+
+fn normalize(answer: &str) -> String {
+    return answer
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string();
+}
+
+/// The parts of `report` whose answer differs from the one in `theirs`,
+/// ignoring trailing whitespace.
+pub fn compare(report: &Report, theirs: &BTreeMap<u32, String>) -> Vec<Disagreement> {
+    return (1..)
+        .zip(&report.parts)
+        .filter_map(|(part, stage)| {
+            let ours = stage.answer.as_deref().map(normalize);
+            let theirs = theirs.get(&part).map(|answer| normalize(answer));
+
+            (ours != theirs).then_some(Disagreement { part, ours, theirs })
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Disagreement;
+    use crate::runner::{Report, Stage, Status};
+    use std::time::Duration;
+
+    #[test]
+    fn parse_output_reads_multi_line_answers() {
+        let output = "debug line
+Part 1: 13140
+Part 2:
+##..##..
+#..#..##
+
+Memory: parse 1 KiB peak, 2 KiB total
+";
+        let answers = super::parse_output(output);
+
+        assert_eq!(answers[&1], "13140");
+        assert_eq!(answers[&2], "##..##..\n#..#..##");
+        assert_eq!(super::parse_output("Part 2:\n#.\n.#")[&2], "#.\n.#");
+        assert!(super::parse_output("nothing").is_empty());
+    }
+
+    #[test]
+    fn compare_reports_disagreements() {
+        let stage = |answer: Option<&str>| Stage {
+            status: match answer {
+                Some(_) => Status::Solved,
+                None => Status::Failed,
+            },
+            answer: answer.map(String::from),
+            time: Duration::ZERO,
+            memory: None,
+        };
+        let report = Report {
+            year: 2022,
+            day: 5,
+            parse: stage(None),
+            parts: vec![stage(Some("CMZ")), stage(None)],
+            render: None,
+            cached: false,
+        };
+
+        let theirs = super::run("cat", "Part 1: CMZ  \nPart 2: MCD\n", None).unwrap();
+        assert_eq!(
+            super::compare(&report, &theirs),
+            vec![Disagreement {
+                part: 2,
+                ours: None,
+                theirs: Some("MCD".to_string()),
+            }]
+        );
+
+        assert_eq!(
+            super::command("../py/{key}.py {year}", 2022, 5),
+            "../py/day05.py 2022"
+        );
+        assert!(super::run("exit 3", "", None).is_err());
+
+        // More output than a pipe holds, within the time limit.
+        let long = super::run(
+            "yes '# debug' | head -c 1000000; echo 'Part 1: 42'",
+            "",
+            Some(Duration::from_secs(10)),
+        )
+        .unwrap();
+        assert_eq!(long[&1], "42");
+        assert!(super::run("sleep 5", "", Some(Duration::from_millis(50))).is_err());
+    }
+}
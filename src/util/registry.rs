@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use crate::config::Params;
+use crate::runner::{self, Report};
+use crate::solution::Solution;
+
+/// A [`Solution`] with its types erased, so that days of every event fit in a
+/// single table.
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub year: u32,
+    pub day: u32,
+    pub parts: u32,
+    /// See [`Solution::HAS_RENDER`].
+    pub has_render: bool,
+    run: fn(String, Params, Option<Duration>) -> Report,
+}
+
+impl Entry {
+    pub const fn of<S: Solution>() -> Entry {
+        return Entry {
+            year: S::YEAR,
+            day: S::DAY,
+            parts: S::PARTS,
+            has_render: S::HAS_RENDER,
+            run: runner::run::<S>,
+        };
+    }
+
+    /// See [`runner::run`].
+    pub fn run(self: &Self, input: String, params: Params, timeout: Option<Duration>) -> Report {
+        (self.run)(input, params, timeout)
+    }
+}
+
+/// Every event of the crate, oldest first.
+const EVENTS: &[&[Entry]] = &[crate::y2022::DAYS];
+
+/// Every registered day, ordered by year and day.
+pub fn entries() -> impl Iterator<Item = &'static Entry> {
+    EVENTS.iter().flat_map(|days| days.iter())
+}
+
+/// The years with at least one registered day.
+pub fn years() -> Vec<u32> {
+    EVENTS
+        .iter()
+        .filter_map(|days| days.first())
+        .map(|entry| entry.year)
+        .collect()
+}
+
+pub fn find(year: u32, day: u32) -> Option<&'static Entry> {
+    entries().find(|entry| entry.year == year && entry.day == day)
+}
+
+/// The registered days of `year`.
+pub fn year(year: u32) -> impl Iterator<Item = &'static Entry> {
+    entries().filter(move |entry| entry.year == year)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn find_looks_up_year_and_day() {
+        let entry = super::find(2022, 25).unwrap();
+
+        assert_eq!((entry.year, entry.day, entry.parts), (2022, 25, 1));
+        assert!(super::find(2022, 26).is_none());
+        assert!(super::find(2015, 1).is_none());
+    }
+
+    #[test]
+    fn entries_are_ordered() {
+        let keys = super::entries()
+            .map(|entry| (entry.year, entry.day))
+            .collect::<Vec<_>>();
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(keys, sorted);
+        assert_eq!(super::years(), vec![2022]);
+        assert_eq!(super::year(2022).count(), 25);
+    }
+}
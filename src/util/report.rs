@@ -0,0 +1,439 @@
+//! `aoc report`: a self-contained HTML page of the results of `aoc run
+//! --format json`, for reviewing a year at a glance.
+//!
+//! Everything comes from files: the reports of the runner, with the pictures
+//! of the days that have a [`crate::solution::Solution::render`], and the
+//! output of `cargo test` for the example tests.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::error::Error;
+use crate::runner::{Report, Stage, Status};
+
+/// How an example test ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+/// An example test of a day, from the output of `cargo test`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Test {
+    pub name: String,
+    pub outcome: Outcome,
+}
+
+/// A day of the report.
+#[derive(Debug, Clone)]
+pub struct Day {
+    pub report: Report,
+    pub verdicts: Vec<Verdict>,
+    pub tests: Vec<Test>,
+}
+
+/// The reports printed by `aoc run --format json`: one JSON object per line,
+/// or a JSON array of them. Other lines, such as the debug output of a day,
+/// are skipped.
+pub fn load_results(text: &str) -> Result<Vec<Report>, Error> {
+    if text.trim_start().starts_with('[') {
+        return serde_json::from_str(text).map_err(|e| Error::ParseError(e.to_string()));
+    }
+
+    return text
+        .lines()
+        .filter(|line| line.trim_start().starts_with('{'))
+        .map(|line| serde_json::from_str(line).map_err(|e| Error::ParseError(e.to_string())))
+        .collect();
+}
+
+/// The tests of every day in the output of `cargo test`, from its
+/// `test yYEAR::dayNN::tests::NAME ... ok` lines.
+pub fn parse_tests(output: &str) -> BTreeMap<(u32, u32), Vec<Test>> {
+    let mut tests = BTreeMap::<_, Vec<_>>::new();
+
+    for line in output.lines() {
+        let Some((path, outcome)) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.split_once(" ... "))
+        else {
+            continue;
+        };
+        let outcome = match outcome.trim() {
+            "ok" => Outcome::Passed,
+            "FAILED" => Outcome::Failed,
+            o if o.starts_with("ignored") => Outcome::Ignored,
+            _ => continue,
+        };
+
+        let mut segments = path.split("::");
+        let (Some(year), Some(day), Some("tests"), Some(name)) = (
+            segments.next(),
+            segments.next(),
+            segments.next(),
+            segments.next(),
+        ) else {
+            continue;
+        };
+        let (Some(Ok(year)), Some(Ok(day))) = (
+            year.strip_prefix('y').map(str::parse::<u32>),
+            day.strip_prefix("day").map(str::parse::<u32>),
+        ) else {
+            continue;
+        };
+
+        tests.entry((year, day)).or_default().push(Test {
+            name: name.to_string(),
+            outcome,
+        });
+    }
+
+    return tests;
+}
+
+/// The days of `reports`, checked against `answers`, with their tests.
+pub fn days(
+    reports: Vec<Report>,
+    answers: &Answers,
+    tests: &BTreeMap<(u32, u32), Vec<Test>>,
+) -> Vec<Day> {
+    return reports
+        .into_iter()
+        .map(|report| Day {
+            verdicts: answers.verify(&report),
+            tests: tests
+                .get(&(report.year, report.day))
+                .cloned()
+                .unwrap_or_default(),
+            report,
+        })
+        .collect();
+}
+
+/// `text` with the characters that mean something in HTML escaped.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    return escaped;
+}
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #ddd; text-align: left; vertical-align: top; }
+pre { font-family: monospace; line-height: 1.1; margin: 0; }
+.correct { color: #1a7f37; }
+.wrong, .failed { color: #cf222e; }
+.unknown, .ignored { color: #777; }
+.bar { display: flex; width: 300px; height: 0.9em; }
+.bar span { display: block; min-width: 1px; }
+.parse { background: #999; }
+.part1 { background: #4c8eda; }
+.part2 { background: #f0a030; }
+";
+
+fn ms(time: Duration) -> String {
+    return format!("{:.3} ms", time.as_secs_f64() * 1000.0);
+}
+
+fn total(report: &Report) -> Duration {
+    return std::iter::once(&report.parse)
+        .chain(&report.parts)
+        .map(|stage| stage.time)
+        .sum();
+}
+
+fn answer(stage: &Stage, verdict: Option<&Verdict>) -> String {
+    let Some(answer) = &stage.answer else {
+        let status = match stage.status {
+            Status::Solved => "solved",
+            Status::Failed => "failed",
+            Status::TimedOut => "timed out",
+            Status::Skipped => "skipped",
+        };
+        return format!("<span class=\"failed\">{}</span>", status);
+    };
+
+    let (class, title) = match verdict {
+        Some(Verdict::Correct) => ("correct", "matches the answer store".to_string()),
+        Some(Verdict::Wrong { expected }) => ("wrong", format!("should be {}", expected)),
+        _ => ("unknown", "not in the answer store".to_string()),
+    };
+
+    return match answer.contains('\n') {
+        true => format!(
+            "<pre class=\"{}\" title=\"{}\">{}</pre>",
+            class,
+            escape(&title),
+            escape(answer)
+        ),
+        false => format!(
+            "<span class=\"{}\" title=\"{}\">{}</span>",
+            class,
+            escape(&title),
+            escape(answer)
+        ),
+    };
+}
+
+/// The timings of a day as a bar of its stages, scaled so that the slowest
+/// day of the report fills it.
+fn bar(report: &Report, slowest: Duration) -> String {
+    let stages = std::iter::once(("parse".to_string(), &report.parse)).chain(
+        (1..)
+            .zip(&report.parts)
+            .map(|(part, stage)| (format!("part{}", part), stage)),
+    );
+
+    let mut bar = String::from("<div class=\"bar\">");
+    for (name, stage) in stages {
+        let width = match slowest.is_zero() {
+            true => 0.0,
+            false => 100.0 * stage.time.as_secs_f64() / slowest.as_secs_f64(),
+        };
+        let _ = write!(
+            bar,
+            "<span class=\"{}\" style=\"width: {:.2}%\" title=\"{} {}\"></span>",
+            name,
+            width,
+            name,
+            ms(stage.time)
+        );
+    }
+    bar.push_str("</div>");
+
+    return bar;
+}
+
+fn examples(tests: &[Test]) -> String {
+    if tests.is_empty() {
+        return String::new();
+    }
+
+    let count = |outcome| tests.iter().filter(|t| t.outcome == outcome).count();
+    let (passed, failed) = (count(Outcome::Passed), count(Outcome::Failed));
+    let names = tests
+        .iter()
+        .map(|t| format!("{}: {:?}", t.name, t.outcome).to_lowercase())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    return match failed {
+        0 => format!(
+            "<span class=\"correct\" title=\"{}\">{} passed</span>",
+            escape(&names),
+            passed
+        ),
+        _ => format!(
+            "<span class=\"failed\" title=\"{}\">{} of {} failed</span>",
+            escape(&names),
+            failed,
+            passed + failed
+        ),
+    };
+}
+
+/// The HTML page of `days`, with its styles inline so that it can be shared
+/// as a single file.
+pub fn html(title: &str, days: &[Day]) -> String {
+    let slowest = days
+        .iter()
+        .map(|day| total(&day.report))
+        .max()
+        .unwrap_or_default();
+    let parts = days
+        .iter()
+        .map(|day| day.report.parts.len())
+        .max()
+        .unwrap_or(0);
+
+    let mut page = String::new();
+    let _ = write!(
+        page,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        escape(title),
+        STYLE,
+        escape(title)
+    );
+
+    page.push_str("<table>\n<tr><th>Day</th>");
+    for part in 1..=parts {
+        let _ = write!(page, "<th>Part {}</th>", part);
+    }
+    page.push_str("<th>Time</th><th></th><th>Examples</th></tr>\n");
+
+    for day in days {
+        let report = &day.report;
+        // Only the days with a picture have a heading to link to.
+        let _ = match report.render {
+            Some(_) => write!(
+                page,
+                "<tr><td><a href=\"#{}\">{}</a></td>",
+                report.name(),
+                escape(&report.name())
+            ),
+            None => write!(page, "<tr><td>{}</td>", escape(&report.name())),
+        };
+        for part in 0..parts {
+            let cell = match report.parts.get(part) {
+                Some(stage) => answer(stage, day.verdicts.get(part)),
+                None => String::new(),
+            };
+            let _ = write!(page, "<td>{}</td>", cell);
+        }
+        let cached = match report.cached {
+            true => " (cached)",
+            false => "",
+        };
+        let _ = writeln!(
+            page,
+            "<td>{}{}</td><td>{}</td><td>{}</td></tr>",
+            ms(total(report)),
+            cached,
+            bar(report, slowest),
+            examples(&day.tests)
+        );
+    }
+    page.push_str("</table>\n");
+
+    for day in days {
+        let Some(render) = &day.report.render else {
+            continue;
+        };
+        let _ = writeln!(
+            page,
+            "<h2 id=\"{}\">{}</h2>\n<pre>{}</pre>",
+            day.report.name(),
+            escape(&day.report.name()),
+            escape(render)
+        );
+    }
+
+    page.push_str("</body>\n</html>\n");
+
+    return page;
+}
+
+/// The title of the report of `reports`: the years they cover.
+pub fn title(reports: &[Report]) -> String {
+    let mut years = reports.iter().map(|r| r.year).collect::<Vec<_>>();
+    years.sort();
+    years.dedup();
+
+    let years = years
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    return format!("Advent of Code {}", years);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day, Outcome, Test};
+    use crate::answers::Verdict;
+    use crate::runner::{Report, Stage, Status};
+    use std::time::Duration;
+
+    fn stage(answer: Option<&str>, ms: u64) -> Stage {
+        Stage {
+            status: match answer {
+                Some(_) => Status::Solved,
+                None => Status::TimedOut,
+            },
+            answer: answer.map(String::from),
+            time: Duration::from_millis(ms),
+            memory: None,
+        }
+    }
+
+    #[test]
+    fn load_results_reads_json_lines() {
+        let line = r#"{"year":2022,"day":10,"parse":{"status":"solved","time_ms":0.1},"parts":[{"status":"solved","answer":"13140","time_ms":0.2},{"status":"timed_out","time_ms":5000.0}]}"#;
+
+        let reports = super::load_results(&format!("{}\n\n{}\n", line, line)).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].parts[0].answer.as_deref(), Some("13140"));
+
+        let array = super::load_results(&format!("[{}]", line)).unwrap();
+        assert_eq!(array, reports[..1]);
+
+        let noisy = super::load_results(&format!("#..#\n{}\n", line)).unwrap();
+        assert_eq!(noisy, reports[..1]);
+        assert!(super::load_results(r#"{"year":2022}"#).is_err());
+    }
+
+    #[test]
+    fn parse_tests_groups_by_day() {
+        let output = "running 3 tests
+test y2022::day10::tests::part1_example1 ... ok
+test y2022::day10::tests::part2_example1 ... FAILED
+test y2022::day17::tests::part2_example1 ... ignored, slow
+test dp::tests::tables_keep_the_best_value ... ok
+";
+        let tests = super::parse_tests(output);
+
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[&(2022, 10)][1].outcome, Outcome::Failed);
+        assert_eq!(
+            tests[&(2022, 17)],
+            vec![Test {
+                name: "part2_example1".to_string(),
+                outcome: Outcome::Ignored,
+            }]
+        );
+    }
+
+    #[test]
+    fn html_escapes_answers_and_renders() {
+        let day = Day {
+            report: Report {
+                year: 2022,
+                day: 10,
+                parse: stage(None, 0),
+                parts: vec![stage(Some("<13140>"), 1), stage(Some("##..\n..##"), 3)],
+                render: Some("#<#".to_string()),
+                cached: false,
+            },
+            verdicts: vec![
+                Verdict::Wrong {
+                    expected: "13140".to_string(),
+                },
+                Verdict::Correct,
+            ],
+            tests: Vec::new(),
+        };
+
+        let mut plain = day.clone();
+        plain.report.day = 11;
+        plain.report.render = None;
+        let page = super::html("Advent of Code 2022", &[day, plain]);
+
+        assert!(page.starts_with("<!DOCTYPE html>"));
+        assert!(
+            page.contains("<span class=\"wrong\" title=\"should be 13140\">&lt;13140&gt;</span>")
+        );
+        assert!(page.contains(
+            "<pre class=\"correct\" title=\"matches the answer store\">##..\n..##</pre>"
+        ));
+        assert!(page.contains("<h2 id=\"2022/day10\">2022/day10</h2>\n<pre>#&lt;#</pre>"));
+        assert!(page.contains("width: 75.00%"));
+        assert!(page.contains("<td><a href=\"#2022/day10\">2022/day10</a></td>"));
+        assert!(page.contains("<tr><td>2022/day11</td>"));
+    }
+}
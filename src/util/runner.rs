@@ -0,0 +1,396 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{Args, Config, Format, Params};
+use crate::error::Error;
+use crate::memory::{Bytes, Usage};
+use crate::registry::Entry;
+use crate::solution::Solution;
+
+/// The solutions recurse deeply, so they get more than the default 2 MiB.
+const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Failed,
+    TimedOut,
+    Skipped,
+}
+
+/// One step of a run: parsing the input or solving one of the parts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Stage {
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub answer: Option<String>,
+    #[serde(rename = "time_ms", with = "millis")]
+    pub time: Duration,
+    /// Measured when [`crate::memory`] profiling is enabled.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub memory: Option<Usage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub parse: Stage,
+    pub parts: Vec<Stage>,
+    /// The picture of [`Solution::render`], drawn from the parsed input once
+    /// every part is solved.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub render: Option<String>,
+    /// Whether the stages come from [`crate::cache::Cache`] rather than from
+    /// solving the input again.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub cached: bool,
+}
+
+impl Report {
+    /// `YEAR/dayNN`, the way the days are named in messages.
+    pub fn name(&self) -> String {
+        format!("{}/{}", self.year, crate::config::day_key(self.day))
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.parts.iter().all(|part| part.status == Status::Solved)
+    }
+}
+
+mod millis {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(time: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(time.as_secs_f64() * 1000.0)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let ms = f64::deserialize(deserializer)?;
+        return Ok(Duration::from_secs_f64(ms / 1000.0));
+    }
+}
+
+/// Solves `input` on a separate thread, giving each stage at most `timeout`.
+///
+/// A stage that panics is reported as failed and one that runs out of time as
+/// timed out; the stages after it are skipped. A timed out thread is left
+/// running in the background, since there is no way to cancel it.
+pub fn run<S: Solution>(input: String, params: Params, timeout: Option<Duration>) -> Report {
+    let (tx, rx) = mpsc::channel::<(Duration, Option<String>, Option<Usage>)>();
+    let (render_tx, render_rx) = mpsc::channel::<Option<String>>();
+
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let profile = crate::memory::is_enabled();
+
+            let mark = crate::memory::mark();
+            let start = Instant::now();
+            let parsed = S::parse(&input, &params);
+            let time = start.elapsed();
+            let memory = profile.then(|| crate::memory::since(mark));
+            if tx.send((time, None, memory)).is_err() {
+                return;
+            }
+
+            for part in 1..=S::PARTS {
+                let mark = crate::memory::mark();
+                let start = Instant::now();
+                let answer = match part {
+                    1 => S::part1(&parsed, &params),
+                    _ => S::part2(&parsed, &params),
+                };
+                let time = start.elapsed();
+                let memory = profile.then(|| crate::memory::since(mark));
+                if tx.send((time, Some(answer), memory)).is_err() {
+                    return;
+                }
+            }
+
+            if S::HAS_RENDER {
+                let _ = render_tx.send(S::render(&parsed, &params));
+            }
+        })
+        .expect("to be able to spawn the solver thread");
+
+    let mut stages = Vec::new();
+    let mut status = Status::Solved;
+
+    for _ in 0..=S::PARTS {
+        if status != Status::Solved {
+            stages.push(Stage {
+                status: Status::Skipped,
+                answer: None,
+                time: Duration::ZERO,
+                memory: None,
+            });
+            continue;
+        }
+
+        let start = Instant::now();
+        let received = match timeout {
+            Some(timeout) => rx.recv_timeout(timeout),
+            None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        let stage = match received {
+            Ok((time, answer, memory)) => Stage {
+                status: Status::Solved,
+                answer,
+                time,
+                memory,
+            },
+            Err(RecvTimeoutError::Timeout) => Stage {
+                status: Status::TimedOut,
+                answer: None,
+                time: start.elapsed(),
+                memory: None,
+            },
+            Err(RecvTimeoutError::Disconnected) => Stage {
+                status: Status::Failed,
+                answer: None,
+                time: start.elapsed(),
+                memory: None,
+            },
+        };
+
+        status = stage.status;
+        stages.push(stage);
+    }
+
+    // A picture is a nicety, so a day that fails to draw one only loses it.
+    let render = match S::HAS_RENDER && status == Status::Solved {
+        true => match timeout {
+            Some(timeout) => render_rx.recv_timeout(timeout).ok().flatten(),
+            None => render_rx.recv().ok().flatten(),
+        },
+        false => None,
+    };
+    let parse = stages.remove(0);
+
+    return Report {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        parts: stages,
+        render,
+        cached: false,
+    };
+}
+
+/// Prints `report` the way the binaries always did: one `Part N: ...` line per
+/// part, with multi-line answers starting on the next line.
+pub fn print(report: &Report, format: Format) {
+    match format {
+        Format::Text => {
+            if report.parse.status != Status::Solved {
+                eprintln!("{}: parse {}", report.name(), describe(&report.parse));
+            }
+
+            for (i, part) in report.parts.iter().enumerate() {
+                match &part.answer {
+                    Some(answer) if answer.contains('\n') => {
+                        println!("Part {}: \n{}", i + 1, answer)
+                    }
+                    Some(answer) => println!("Part {}: {}", i + 1, answer),
+                    None if part.status == Status::Skipped => (),
+                    None => eprintln!("{}: part {} {}", report.name(), i + 1, describe(part)),
+                }
+            }
+
+            let stages = std::iter::once(("parse".to_string(), &report.parse)).chain(
+                (1..)
+                    .zip(&report.parts)
+                    .map(|(i, part)| (format!("part {}", i), part)),
+            );
+            let memory = stages
+                .filter_map(|(name, stage)| {
+                    let usage = stage.memory?;
+                    Some(format!(
+                        "{} {} peak, {} total",
+                        name,
+                        Bytes(usage.peak),
+                        Bytes(usage.total)
+                    ))
+                })
+                .collect::<Vec<_>>();
+            // Apart from the answers, which scripts read from stdout.
+            if !memory.is_empty() {
+                eprintln!("{}: memory: {}", report.name(), memory.join("; "));
+            }
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(report).expect("reports to be serializable")
+        ),
+    }
+}
+
+fn describe(stage: &Stage) -> String {
+    match stage.status {
+        Status::Solved => "solved".to_string(),
+        Status::Failed => "failed".to_string(),
+        Status::TimedOut => format!("timed out after {:.1}s", stage.time.as_secs_f64()),
+        Status::Skipped => "skipped".to_string(),
+    }
+}
+
+/// The `main` of every `dayNN` binary.
+pub fn main<S: Solution>() {
+    main_with::<S>(Args::parse());
+}
+
+/// [`main`] with options already parsed, for binaries with options of their
+/// own.
+pub fn main_with<S: Solution>(args: Args) {
+    let config = Config::load(&args)
+        .and_then(|config| config.init_log().map(|_| config))
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        });
+
+    let input = crate::input::read_input(&config, S::YEAR, S::DAY).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(2);
+    });
+
+    let report = crate::cache::run(&Entry::of::<S>(), input, &config);
+    print(&report, config.format);
+
+    if !report.is_solved() {
+        std::process::exit(1);
+    }
+}
+
+/// The `main` of the modes of a `dayNN` binary other than solving, such as
+/// `day04 --query`: loads the configuration, runs `mode` within the time
+/// limit and prints what it returns, as is or in a JSON object with the
+/// year and the day. Its output is not cached, as it may read its input as
+/// a stream that cannot be read twice. A mode that prints its output as it
+/// goes, such as `day06 --all`, returns an empty one, which is not printed.
+pub fn side_mode<S: Solution>(
+    args: Args,
+    mode: impl FnOnce(Config) -> Result<String, Error> + Send + 'static,
+) {
+    let config = Config::load(&args)
+        .and_then(|config| config.init_log().map(|_| config))
+        .unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        });
+
+    let (format, timeout) = (config.format, config.timeout);
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _ = tx.send(mode(config));
+        })
+        .expect("to be able to spawn the solver thread");
+
+    let received = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    };
+
+    let output = match received {
+        Ok(Ok(output)) => output,
+        Ok(Err(e)) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            eprintln!(
+                "{}/{}: timed out after {:.1}s",
+                S::YEAR,
+                crate::config::day_key(S::DAY),
+                timeout.unwrap_or_default().as_secs_f64()
+            );
+            std::process::exit(1);
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            eprintln!("{}/{}: failed", S::YEAR, crate::config::day_key(S::DAY));
+            std::process::exit(1);
+        }
+    };
+
+    match format {
+        _ if output.is_empty() => {}
+        Format::Text => println!("{}", output),
+        Format::Json => println!(
+            "{}",
+            serde_json::json!({ "year": S::YEAR, "day": S::DAY, "output": output })
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Status;
+    use crate::config::Params;
+    use crate::solution::Solution;
+    use std::time::Duration;
+
+    struct Sleepy;
+
+    impl Solution for Sleepy {
+        type Input<'a> = u64;
+
+        const YEAR: u32 = 2022;
+        const DAY: u32 = 1;
+
+        fn parse<'a>(input: &'a str, _: &Params) -> Self::Input<'a> {
+            input.trim().parse().unwrap()
+        }
+
+        fn part1(input: &Self::Input<'_>, params: &Params) -> String {
+            (input * params.get("factor", 2)).to_string()
+        }
+
+        fn part2(input: &Self::Input<'_>, _: &Params) -> String {
+            std::thread::sleep(Duration::from_millis(*input));
+            input.to_string()
+        }
+    }
+
+    #[test]
+    fn run_reports_each_part() {
+        let mut params = Params::default();
+        params.set("factor", "3");
+
+        let report = super::run::<Sleepy>("5".to_string(), params, None);
+
+        assert!(report.is_solved());
+        assert_eq!(report.parts[0].answer.as_deref(), Some("15"));
+        assert_eq!(report.parts[1].answer.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn run_times_out() {
+        let report = super::run::<Sleepy>(
+            "1000".to_string(),
+            Params::default(),
+            Some(Duration::from_millis(50)),
+        );
+
+        assert_eq!(report.parts[0].status, Status::Solved);
+        assert_eq!(report.parts[1].status, Status::TimedOut);
+    }
+
+    #[test]
+    fn run_reports_panics_as_failures() {
+        let report = super::run::<Sleepy>("oops".to_string(), Params::default(), None);
+
+        assert_eq!(report.parse.status, Status::Failed);
+        assert_eq!(report.parts[0].status, Status::Skipped);
+        assert!(!report.is_solved());
+    }
+}
@@ -0,0 +1,349 @@
+//! `aoc new`: generates the module of a new day from [`TEMPLATE`], with its
+//! binary, and registers it.
+
+use std::path::{Path, PathBuf};
+
+use crate::config::day_key;
+use crate::error::Error;
+
+/// The module of a new day; `{year}` and `{day}` are replaced, and `{Key}` by
+/// the name of its [`crate::solution::Solution`].
+pub const TEMPLATE: &str = r#"//! Day {day}.
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<String> {
+    return input.as_ref().lines().map(String::from).collect();
+}
+
+pub fn part1(input: &Vec<String>) -> String {
+    return input.len().to_string();
+}
+
+pub fn part2(input: &Vec<String>) -> String {
+    return input.len().to_string();
+}
+
+/// Day {day} for the runner, see [`crate::solution::Solution`].
+pub struct {Key};
+
+impl crate::solution::Solution for {Key} {
+    type Input<'a> = Vec<String>;
+
+    const YEAR: u32 = {year};
+    const DAY: u32 = {day};
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE1: &str = "";
+
+    #[test]
+    #[ignore = "the example is missing"]
+    fn part1_example1() {
+        let input = super::parse_input(EXAMPLE1);
+
+        assert_eq!(super::part1(&input), "");
+    }
+
+    #[test]
+    #[ignore = "the example is missing"]
+    fn part2_example1() {
+        let input = super::parse_input(EXAMPLE1);
+
+        assert_eq!(super::part2(&input), "");
+    }
+}
+"#;
+
+/// The binary of a new day, which runs it alone.
+const BIN_TEMPLATE: &str = r#"fn main() {
+    aoc::runner::main::<aoc::y{year}::{key}::{Key}>();
+}
+"#;
+
+/// The module of a new event, with its first day.
+const EVENT_TEMPLATE: &str = r#"//! The puzzles of Advent of Code {year}, one module per day.
+
+pub mod {key};
+
+/// The days of the event, for [`crate::registry`].
+pub const DAYS: &[crate::registry::Entry] = &[
+    crate::registry::Entry::of::<{key}::{Key}>(),
+];
+"#;
+
+fn fill(template: &str, year: u32, day: u32) -> String {
+    return template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{key}", &day_key(day))
+        .replace("{Key}", &format!("Day{:02}", day));
+}
+
+/// [`TEMPLATE`] for `day` of `year`.
+pub fn render(year: u32, day: u32) -> String {
+    return fill(TEMPLATE, year, day);
+}
+
+/// Inserts `line` among the lines of `file` that start with `prefix` once
+/// trimmed, keeping them sorted.
+fn insert_sorted(file: &Path, text: &str, line: &str, prefix: &str) -> Result<String, Error> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+
+    let similar = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.trim_start().starts_with(prefix))
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    let at = match similar.iter().find(|&&i| lines[i] > line) {
+        Some(&i) => i,
+        None => match similar.last() {
+            Some(&i) => i + 1,
+            None => {
+                return Err(Error::ParseError(format!(
+                    "no `{}` line in {}",
+                    prefix,
+                    file.display()
+                )))
+            }
+        },
+    };
+    lines.insert(at, line);
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+fn read(path: &Path) -> Result<String, Error> {
+    return crate::input::read_file(path);
+}
+
+/// Creates `file` with `contents`, failing when it already exists.
+fn create(file: &Path, contents: &str) -> Result<(), Error> {
+    if let Some(parent) = file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    return std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(file)
+        .and_then(|mut f| std::io::Write::write_all(&mut f, contents.as_bytes()))
+        .map_err(|e| Error::IoError(format!("failed to create {}: {}", file.display(), e)));
+}
+
+/// Adds `day` of `year` to the crate at `root`: its module, generated from
+/// [`TEMPLATE`], its entry in the registry, its binary `src/bin/dayNN.rs`, or
+/// `src/bin/yYEAR-dayNN.rs` when another event has that one, and an empty
+/// `examples` file for the example of the puzzle text. The module of the event
+/// is created as well when this is its first day.
+///
+/// Refuses to touch anything when the module of the day already exists.
+/// Returns the files that were created or changed.
+pub fn new_day(root: &Path, examples: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::ConfigError(format!("there is no day {}", day)));
+    }
+
+    let util = root.join("src").join("util");
+    let lib = util.join("lib.rs");
+    if !lib.exists() {
+        return Err(Error::ConfigError(format!(
+            "{} is not the root of the crate",
+            root.display()
+        )));
+    }
+
+    let event = format!("y{}", year);
+    let key = day_key(day);
+    let module = util.join(&event).join(format!("{}.rs", key));
+    if module.exists() {
+        return Err(Error::ConfigError(format!(
+            "{} already exists",
+            module.display()
+        )));
+    }
+
+    let bins = root.join("src").join("bin");
+    let bin = [key.clone(), format!("{}-{}", event, key)]
+        .into_iter()
+        .map(|name| bins.join(format!("{}.rs", name)))
+        .find(|bin| !bin.exists())
+        .ok_or(Error::ConfigError(format!(
+            "the binary of {} day {} already exists in {}",
+            year,
+            day,
+            bins.display()
+        )))?;
+
+    // Every file is prepared before any is written, so that a registry that
+    // cannot be edited leaves the crate as it was.
+    let event_module = util.join(&event).join("mod.rs");
+    let mut edits = Vec::new();
+    let mut created = vec![
+        (module, render(year, day)),
+        (bin, fill(BIN_TEMPLATE, year, day)),
+    ];
+
+    if event_module.exists() {
+        let mut text = read(&event_module)?;
+        text = insert_sorted(
+            &event_module,
+            &text,
+            &format!("pub mod {};", key),
+            "pub mod day",
+        )?;
+        text = insert_sorted(
+            &event_module,
+            &text,
+            &format!(
+                "    crate::registry::Entry::of::<{}::Day{:02}>(),",
+                key, day
+            ),
+            "crate::registry::Entry::of::",
+        )?;
+        edits.push((event_module, text));
+    } else {
+        created.push((event_module, fill(EVENT_TEMPLATE, year, day)));
+
+        let text = insert_sorted(
+            &lib,
+            &read(&lib)?,
+            &format!("pub mod {};", event),
+            "pub mod y",
+        )?;
+        edits.push((lib, text));
+
+        let registry = util.join("registry.rs");
+        let text = read(&registry)?;
+        let (before, after) =
+            text.split_once("const EVENTS: &[&[Entry]] = &[")
+                .ok_or(Error::ParseError(format!(
+                    "no EVENTS in {}",
+                    registry.display()
+                )))?;
+        let (events, after) = after.split_once("];").ok_or(Error::ParseError(format!(
+            "unterminated EVENTS in {}",
+            registry.display()
+        )))?;
+        let mut events = events
+            .split(',')
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        events.push(format!("crate::{}::DAYS", event));
+        events.sort();
+
+        let text = format!(
+            "{}const EVENTS: &[&[Entry]] = &[{}];{}",
+            before,
+            events.join(", "),
+            after
+        );
+        edits.push((registry, text));
+    }
+
+    if !examples.exists() {
+        created.push((examples.to_path_buf(), String::new()));
+    }
+
+    let mut changed = Vec::new();
+    for (file, contents) in created {
+        create(&file, &contents)?;
+        changed.push(file);
+    }
+    for (file, contents) in edits {
+        std::fs::write(&file, contents)?;
+        changed.push(file);
+    }
+
+    return Ok(changed);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    fn write(path: &Path, text: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, text).unwrap();
+    }
+
+    #[test]
+    fn new_day_registers_the_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let util = root.join("src/util");
+        write(&util.join("lib.rs"), "pub mod registry;\npub mod y2022;\n");
+        write(
+            &util.join("registry.rs"),
+            "const EVENTS: &[&[Entry]] = &[crate::y2022::DAYS];\n",
+        );
+
+        let examples = root.join("input/2023/day02.example");
+        let changed = super::new_day(&root, &examples, 2023, 2).unwrap();
+        assert_eq!(changed.len(), 6);
+        assert_eq!(
+            super::read(&examples).unwrap(),
+            "",
+            "the examples file is empty"
+        );
+
+        super::new_day(&root, &root.join("day01.example"), 2023, 1).unwrap();
+
+        let lib = super::read(&util.join("lib.rs")).unwrap();
+        assert_eq!(lib, "pub mod registry;\npub mod y2022;\npub mod y2023;\n");
+
+        let registry = super::read(&util.join("registry.rs")).unwrap();
+        assert!(registry.contains("&[crate::y2022::DAYS, crate::y2023::DAYS];"));
+
+        let event = super::read(&util.join("y2023/mod.rs")).unwrap();
+        assert!(event.contains(
+            "pub mod day01;
+pub mod day02;
+"
+        ));
+        assert!(event.contains(
+            "    crate::registry::Entry::of::<day01::Day01>(),
+    crate::registry::Entry::of::<day02::Day02>(),
+];"
+        ));
+
+        let module = super::read(&util.join("y2023/day02.rs")).unwrap();
+        assert!(module.contains("pub struct Day02;"));
+        assert!(module.contains("const YEAR: u32 = 2023;"));
+
+        let bin = super::read(&root.join("src/bin/day02.rs")).unwrap();
+        assert_eq!(
+            bin,
+            "fn main() {\n    aoc::runner::main::<aoc::y2023::day02::Day02>();\n}\n"
+        );
+
+        // Day 1 of 2023 has the binary `day01` already.
+        super::new_day(&root, &root.join("day01.example"), 2024, 1).unwrap();
+        let bin = super::read(&root.join("src/bin/y2024-day01.rs")).unwrap();
+        assert!(bin.contains("aoc::y2024::day01::Day01"));
+
+        std::fs::write(util.join("y2023/day02.rs"), "// solved").unwrap();
+        assert!(super::new_day(&root, &examples, 2023, 2).is_err());
+        assert_eq!(
+            super::read(&util.join("y2023/day02.rs")).unwrap(),
+            "// solved"
+        );
+        assert!(super::new_day(&root, &examples, 2023, 26).is_err());
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
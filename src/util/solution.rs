@@ -0,0 +1,31 @@
+use crate::config::Params;
+
+/// A day of one of the events, as seen by the runner.
+///
+/// `parse` runs once and its result is shared by both parts. The parameters
+/// come from `aoc.toml` and the `--param` flags; every day falls back to the
+/// values of the real puzzle when they are missing.
+pub trait Solution: 'static {
+    type Input<'a>;
+
+    const YEAR: u32;
+    const DAY: u32;
+    const PARTS: u32 = 2;
+    /// Whether the day overrides [`Solution::render`], which the runner only
+    /// calls for those.
+    const HAS_RENDER: bool = false;
+
+    fn parse<'a>(input: &'a str, params: &Params) -> Self::Input<'a>;
+
+    fn part1(input: &Self::Input<'_>, params: &Params) -> String;
+
+    fn part2(_input: &Self::Input<'_>, _params: &Params) -> String {
+        unreachable!("day {} of {} has a single part", Self::DAY, Self::YEAR)
+    }
+
+    /// A picture of the puzzle as ASCII art, for `aoc report`; most days have
+    /// none.
+    fn render(_input: &Self::Input<'_>, _params: &Params) -> Option<String> {
+        None
+    }
+}
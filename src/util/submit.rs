@@ -0,0 +1,321 @@
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::error::Error;
+
+/// What the site made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// An answer was submitted too recently; the next one can be sent after
+    /// `seconds`.
+    Wait {
+        seconds: u64,
+    },
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait { seconds } => write!(f, "wait {}s before trying again", seconds),
+            Outcome::WrongLevel => write!(f, "already solved or not unlocked"),
+        }
+    }
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the site answers with.
+    pub fn from_page(page: &str) -> Result<Outcome, Error> {
+        let page = page.to_ascii_lowercase();
+
+        if page.contains("that's the right answer") {
+            return Ok(Outcome::Correct);
+        }
+        if page.contains("your answer is too high") {
+            return Ok(Outcome::TooHigh);
+        }
+        if page.contains("your answer is too low") {
+            return Ok(Outcome::TooLow);
+        }
+        if page.contains("that's not the right answer") {
+            return Ok(Outcome::Wrong);
+        }
+        if page.contains("you gave an answer too recently") {
+            return Ok(Outcome::Wait {
+                seconds: wait_time(&page).unwrap_or(60),
+            });
+        }
+        if page.contains("you don't seem to be solving the right level") {
+            return Ok(Outcome::WrongLevel);
+        }
+
+        return Err(Error::HttpError(
+            "could not understand the answer of the site".to_string(),
+        ));
+    }
+
+    pub fn is_wrong(self: &Self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+/// Reads `you have 4m 2s left to wait` as 242 seconds.
+fn wait_time(page: &str) -> Option<u64> {
+    let start = page.find("you have ")? + "you have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    return page[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(value * 60 * 60),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum();
+}
+
+/// A submitted answer and what the site made of it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+}
+
+/// Every answer submitted so far, stored as a list of `[[attempts]]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    pub attempts: Vec<Attempt>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_secs()
+}
+
+impl FromStr for History {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return toml::from_str(s).map_err(|e| Error::ParseError(e.to_string()));
+    }
+}
+
+impl History {
+    /// Loads the history from `path`; a missing file holds no attempts.
+    pub fn load(path: impl AsRef<Path>) -> Result<History, Error> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        return std::fs::read_to_string(path)?.parse::<History>();
+    }
+
+    pub fn save(self: &Self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let text = toml::to_string(self).map_err(|e| Error::IoError(e.to_string()))?;
+        std::fs::write(path, text)?;
+
+        return Ok(());
+    }
+
+    /// The attempts for `part` of `day` of `year`, oldest first.
+    pub fn attempts(self: &Self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Explains why `answer` should not be submitted, given the earlier
+    /// attempts: the part is solved, the answer is known to be wrong or falls
+    /// outside the bracket left by the too high and too low ones, or the site
+    /// asked to wait.
+    pub fn check(
+        self: &Self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Error> {
+        let refuse = |message: String| Err(Error::SubmitError(message));
+        let value = answer.parse::<i64>().ok();
+
+        for attempt in self.attempts(year, day, part) {
+            match attempt.outcome {
+                Outcome::Correct => {
+                    return refuse(format!("already solved with {}", attempt.answer))
+                }
+                outcome if outcome.is_wrong() && attempt.answer == answer => {
+                    return refuse(format!("{} was already rejected", answer))
+                }
+                Outcome::Wait { seconds } if attempt.time + seconds > now => {
+                    return refuse(format!(
+                        "the site asked to wait {}s more",
+                        attempt.time + seconds - now
+                    ))
+                }
+                _ => (),
+            }
+
+            let bound = attempt.answer.parse::<i64>().ok();
+            match (attempt.outcome, value, bound) {
+                (Outcome::TooHigh, Some(value), Some(bound)) if value >= bound => {
+                    return refuse(format!("{} was already too high", bound))
+                }
+                (Outcome::TooLow, Some(value), Some(bound)) if value <= bound => {
+                    return refuse(format!("{} was already too low", bound))
+                }
+                _ => (),
+            }
+        }
+
+        return Ok(());
+    }
+
+    /// Submits `answer` through `client` unless [`History::check`] refuses it,
+    /// and records the attempt.
+    pub fn submit(
+        self: &mut Self,
+        client: &Client,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<Outcome, Error> {
+        let answer = answer.trim();
+        if answer.is_empty() || answer.contains('\n') {
+            return Err(Error::SubmitError(format!(
+                "{:?} cannot be submitted",
+                answer
+            )));
+        }
+
+        self.check(year, day, part, answer, now())?;
+
+        let page = client.post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        let outcome = Outcome::from_page(&page)?;
+
+        self.attempts.push(Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            time: now(),
+        });
+
+        return Ok(outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{History, Outcome};
+    use crate::client::{stub, Client};
+    use std::time::Duration;
+
+    const CORRECT: &str =
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>";
+    const TOO_HIGH: &str =
+        "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    const TOO_LOW: &str =
+        "<article><p>That's not the right answer; your answer is too low.</p></article>";
+    const WAIT: &str =
+        "<article><p>You gave an answer too recently. You have 4m 2s left to wait.</p></article>";
+
+    #[test]
+    fn from_page_reads_every_outcome() {
+        assert_eq!(Outcome::from_page(CORRECT).unwrap(), Outcome::Correct);
+        assert_eq!(Outcome::from_page(TOO_HIGH).unwrap(), Outcome::TooHigh);
+        assert_eq!(Outcome::from_page(TOO_LOW).unwrap(), Outcome::TooLow);
+        assert_eq!(
+            Outcome::from_page(WAIT).unwrap(),
+            Outcome::Wait { seconds: 242 }
+        );
+        assert!(Outcome::from_page("<html>Log in</html>").is_err());
+    }
+
+    #[test]
+    fn submit_records_attempts_and_refuses_known_wrong_answers() {
+        let (url, server) = stub::serve(vec![(200, TOO_HIGH), (200, TOO_LOW), (200, CORRECT)]);
+        let client = Client::new(url, "abc", Duration::ZERO);
+        let mut history = History::default();
+
+        assert_eq!(
+            history.submit(&client, 2022, 1, 1, "100").unwrap(),
+            Outcome::TooHigh
+        );
+        assert!(history.submit(&client, 2022, 1, 1, "100").is_err());
+        assert!(history.submit(&client, 2022, 1, 1, "150").is_err());
+
+        assert_eq!(
+            history.submit(&client, 2022, 1, 1, "10").unwrap(),
+            Outcome::TooLow
+        );
+        assert!(history.submit(&client, 2022, 1, 1, "5").is_err());
+
+        assert_eq!(
+            history.submit(&client, 2022, 1, 1, "50").unwrap(),
+            Outcome::Correct
+        );
+        assert!(history.submit(&client, 2022, 1, 1, "51").is_err());
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 3);
+        assert!(requests[0].starts_with("POST /2022/day/1/answer "));
+        assert!(requests[0].ends_with("level=1&answer=100"));
+
+        let text = toml::to_string(&history).unwrap();
+        assert_eq!(text.parse::<History>().unwrap(), history);
+    }
+
+    #[test]
+    fn check_waits_when_asked_to() {
+        let history = "[[attempts]]
+year = 2022
+day = 1
+part = 2
+answer = \"7\"
+outcome = { wait = { seconds = 60 } }
+time = 1000
+"
+        .parse::<History>()
+        .unwrap();
+
+        assert!(history.check(2022, 1, 2, "8", 1030).is_err());
+        assert!(history.check(2022, 1, 2, "8", 1060).is_ok());
+        assert!(history.check(2022, 1, 1, "8", 1030).is_ok());
+    }
+}
@@ -0,0 +1,554 @@
+//! Day 1: Calorie Counting.
+//!
+//! The input lists the calories of the items carried by each elf, with the
+//! elves separated by blank lines.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{BufRead, BufReader, Read};
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// The elves whose calories part 1 adds up; the `top_1` parameter.
+pub const TOP_1: usize = 1;
+/// The elves whose calories part 2 adds up; the `top_2` parameter.
+pub const TOP_2: usize = 3;
+
+fn overflow(number: usize) -> Error {
+    return Error::ParseError(format!(
+        "elf {} carries more than {} calories",
+        number,
+        u32::MAX
+    ));
+}
+
+/// An elf and the calories of the items it carries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elf {
+    /// The position of the elf in the input, from 1 as in the puzzle text.
+    pub number: usize,
+    pub items: Vec<u32>,
+    total: u32,
+}
+
+impl Elf {
+    /// Fails when the total does not fit a `u32`.
+    pub fn new(number: usize, items: Vec<u32>) -> Result<Elf, Error> {
+        let total = items
+            .iter()
+            .try_fold(0u32, |total, &item| total.checked_add(item))
+            .ok_or_else(|| overflow(number))?;
+
+        return Ok(Elf {
+            number,
+            items,
+            total,
+        });
+    }
+
+    pub fn total(self: &Self) -> u32 {
+        return self.total;
+    }
+}
+
+/// Every elf of the input, in the order of the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+}
+
+impl FromStr for Inventory {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let elves = s
+            .trim_end()
+            .split("\n\n")
+            .filter(|elf| !elf.trim().is_empty())
+            .enumerate()
+            .map(|(i, elf)| {
+                // More than one blank line between elves leaves some here.
+                let items = elf
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| {
+                        line.trim().parse::<u32>().map_err(|e| {
+                            Error::ParseError(format!("elf {}: {:?}: {}", i + 1, line, e))
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Elf::new(i + 1, items)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        return Ok(Inventory { elves });
+    }
+}
+
+impl Inventory {
+    pub fn elves(self: &Self) -> &[Elf] {
+        return &self.elves;
+    }
+
+    /// The elf numbered `number`, from 1.
+    pub fn get(self: &Self, number: usize) -> Option<&Elf> {
+        return self.elves.get(number.checked_sub(1)?);
+    }
+
+    /// The elves from the one carrying the most calories to the one carrying
+    /// the least; elves carrying as much keep the order of the input.
+    pub fn ranked(self: &Self) -> Vec<&Elf> {
+        let mut ranked = self.elves.iter().collect::<Vec<_>>();
+        ranked.sort_by(|a, b| b.total.cmp(&a.total).then(a.number.cmp(&b.number)));
+
+        return ranked;
+    }
+
+    /// The `k` elves carrying the most calories, or every elf when there are
+    /// fewer.
+    pub fn top(self: &Self, k: usize) -> Vec<&Elf> {
+        let mut ranked = self.ranked();
+        ranked.truncate(k);
+
+        return ranked;
+    }
+
+    /// The `k` elves carrying the most calories, and every other elf carrying
+    /// as much as the last of them.
+    pub fn top_with_ties(self: &Self, k: usize) -> Vec<&Elf> {
+        let ranked = self.ranked();
+        if k == 0 || ranked.is_empty() {
+            return Vec::new();
+        }
+
+        let least = ranked[k.min(ranked.len()) - 1].total;
+        return ranked
+            .into_iter()
+            .take_while(|elf| elf.total >= least)
+            .collect();
+    }
+
+    /// The calories carried by the `k` elves carrying the most.
+    pub fn sum_top(self: &Self, k: usize) -> u64 {
+        return self.top(k).iter().map(|elf| elf.total as u64).sum();
+    }
+
+    /// The total of the elf at percentile `p` (0 to 100) of the totals, by
+    /// the nearest-rank method; `None` for no elves or a `p` out of range.
+    pub fn percentile(self: &Self, p: f64) -> Option<u32> {
+        if self.elves.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+
+        let mut totals = self.elves.iter().map(Elf::total).collect::<Vec<_>>();
+        totals.sort();
+
+        let rank = ((p / 100.0) * totals.len() as f64).ceil() as usize;
+        return Some(totals[rank.max(1) - 1]);
+    }
+
+    /// The number of elves whose total falls in each bucket of `width`
+    /// calories, as `(first calorie of the bucket, elves)`, from the bucket of
+    /// the smallest total to the one of the largest, empty ones included.
+    pub fn histogram(self: &Self, width: u32) -> Vec<(u32, usize)> {
+        let width = width.max(1);
+        let (Some(min), Some(max)) = (
+            self.elves.iter().map(Elf::total).min(),
+            self.elves.iter().map(Elf::total).max(),
+        ) else {
+            return Vec::new();
+        };
+
+        let first = min / width;
+        let mut buckets = (first..=max / width)
+            .map(|bucket| (bucket * width, 0))
+            .collect::<Vec<_>>();
+        for elf in &self.elves {
+            buckets[(elf.total / width - first) as usize].1 += 1;
+        }
+
+        return buckets;
+    }
+}
+
+/// The `n` elves carrying the most calories in the list read from `reader`,
+/// as `(number, total)` ranked like [`Inventory::ranked`].
+///
+/// Reads one line at a time and holds only `n` elves, in a min-heap whose
+/// root is the elf to drop next, so lists of any size fit in constant memory.
+pub fn stream_top(reader: impl Read, n: usize) -> Result<Vec<(usize, u32)>, Error> {
+    // The smallest total first, and the last elf among equal totals.
+    let mut heap = BinaryHeap::<Reverse<(u32, Reverse<usize>)>>::new();
+    let mut keep = |number: usize, total: u32| {
+        heap.push(Reverse((total, Reverse(number))));
+        if heap.len() > n {
+            heap.pop();
+        }
+    };
+
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+    let mut elves = 0;
+    let mut total: Option<u32> = None;
+
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line)?;
+        let item = line.trim();
+
+        if item.is_empty() {
+            if let Some(total) = total.take() {
+                keep(elves, total);
+            }
+            if read == 0 {
+                break;
+            }
+            continue;
+        }
+
+        if total.is_none() {
+            elves += 1;
+        }
+        let calories = item
+            .parse::<u32>()
+            .map_err(|e| Error::ParseError(format!("elf {}: {:?}: {}", elves, item, e)))?;
+        total = Some(
+            total
+                .unwrap_or(0)
+                .checked_add(calories)
+                .ok_or_else(|| overflow(elves))?,
+        );
+    }
+
+    return Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(number)))| (number, total))
+        .collect());
+}
+
+/// Both answers for the list read from `reader`, with [`stream_top`], for
+/// lists too large for [`parse_input`].
+pub fn stream(reader: impl Read, top_1: usize, top_2: usize) -> Result<(String, String), Error> {
+    let top = stream_top(reader, top_1.max(top_2))?;
+    let sum = |k: usize| -> u64 { top.iter().take(k).map(|&(_, total)| total as u64).sum() };
+
+    return Ok((sum(top_1).to_string(), sum(top_2).to_string()));
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Inventory {
+    return input.as_ref().parse().unwrap();
+}
+
+fn list(elves: &[&Elf]) -> String {
+    return elves
+        .iter()
+        .map(|elf| format!("elf {} ({})", elf.number, elf.total))
+        .collect::<Vec<_>>()
+        .join(", ");
+}
+
+/// The `k` elves carrying the most, and those tied with them, for the debug
+/// channel.
+fn describe(inventory: &Inventory, k: usize) -> String {
+    let top = inventory.top_with_ties(k);
+    let elves = list(&top);
+
+    return match top.len() > k {
+        true => format!("{}, tied beyond the top {}", elves, k),
+        false => elves,
+    };
+}
+
+/// The quartiles of the totals and their histogram in ten buckets, for the
+/// debug channel.
+fn statistics(inventory: &Inventory) -> String {
+    let quartiles = [25.0, 50.0, 75.0]
+        .map(|p| inventory.percentile(p).unwrap_or_default().to_string())
+        .join(", ");
+
+    let max = inventory.elves().iter().map(Elf::total).max().unwrap_or(0);
+    let histogram = inventory
+        .histogram(max.div_ceil(10))
+        .into_iter()
+        .map(|(start, elves)| format!("{:>8} {}", start, "#".repeat(elves)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    return format!("the quartiles of the totals: {}\n{}", quartiles, histogram);
+}
+
+/// What `day01` reports on the inventory instead of the answers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Query {
+    /// The `k` elves carrying the most and their calories, with those tied
+    /// with the last of them when `ties` is set.
+    Top { k: usize, ties: bool },
+    /// The total at a percentile of the totals, from 0 to 100.
+    Percentile(f64),
+    /// The number of elves in each bucket of this many calories.
+    Histogram(u32),
+    /// An elf, by its number from 1.
+    Elf(usize),
+}
+
+/// The answer to `query`, on one line or one per bucket of a histogram.
+pub fn query(inventory: &Inventory, query: Query) -> Result<String, Error> {
+    return match query {
+        Query::Top { k, ties } => {
+            let top = match ties {
+                true => inventory.top_with_ties(k),
+                false => inventory.top(k),
+            };
+            Ok(format!(
+                "Top {}: {}; {} calories",
+                k,
+                list(&top),
+                inventory.sum_top(k)
+            ))
+        }
+        Query::Percentile(p) => match inventory.percentile(p) {
+            Some(total) => Ok(format!("Percentile {}: {}", p, total)),
+            None => Err(Error::ConfigError(format!(
+                "no percentile {} of {} elves",
+                p,
+                inventory.elves().len()
+            ))),
+        },
+        Query::Histogram(width) => Ok(inventory
+            .histogram(width)
+            .into_iter()
+            .map(|(start, elves)| format!("{:>8} {:>6}", start, elves))
+            .collect::<Vec<_>>()
+            .join("\n")),
+        Query::Elf(number) => {
+            let elf = inventory
+                .get(number)
+                .ok_or_else(|| Error::ConfigError(format!("there is no elf {}", number)))?;
+            let ranked = inventory.ranked();
+            let rank = ranked.iter().position(|e| e.number == number).unwrap_or(0) + 1;
+            Ok(format!(
+                "Elf {}: {} calories in {} items, rank {} of {}",
+                number,
+                elf.total,
+                elf.items.len(),
+                rank,
+                ranked.len()
+            ))
+        }
+    };
+}
+
+/// The calories carried by the `top` elves carrying the most: the one
+/// carrying the most for the puzzle.
+pub fn part1(inventory: &Inventory, top: usize) -> String {
+    crate::info!("the top {}: {}", top, describe(inventory, top));
+    crate::debug!("{}", statistics(inventory));
+    return inventory.sum_top(top).to_string();
+}
+
+/// The calories carried by the `top` elves carrying the most: the top three
+/// for the puzzle.
+pub fn part2(inventory: &Inventory, top: usize) -> String {
+    crate::info!("the top {}: {}", top, describe(inventory, top));
+    return inventory.sum_top(top).to_string();
+}
+
+/// Day 1 for the runner, see [`crate::solution::Solution`].
+pub struct Day01;
+
+impl crate::solution::Solution for Day01 {
+    type Input<'a> = Inventory;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 1;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("top_1", TOP_1))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("top_2", TOP_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const EXAMPLE1: &str = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(String::from(EXAMPLE1));
+
+        assert_eq!(super::part1(&input, super::TOP_1), "24000");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(String::from(EXAMPLE1));
+
+        assert_eq!(super::part2(&input, super::TOP_2), "45000");
+    }
+
+    #[test]
+    fn inventory_keeps_the_elves() {
+        let inventory = super::parse_input(EXAMPLE1);
+
+        let top = inventory.top(2);
+        assert_eq!(
+            top.iter().map(|elf| elf.number).collect::<Vec<_>>(),
+            vec![4, 3]
+        );
+        assert_eq!(inventory.get(3).unwrap().items, vec![5000, 6000]);
+        assert_eq!(inventory.get(0), None);
+        assert_eq!(inventory.sum_top(10), 55000);
+
+        assert_eq!(inventory.percentile(50.0), Some(10000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+        assert_eq!(inventory.percentile(101.0), None);
+
+        assert_eq!(
+            inventory.histogram(10000),
+            vec![(0, 2), (10000, 2), (20000, 1)]
+        );
+    }
+
+    #[test]
+    fn top_with_ties_includes_the_equals() {
+        let inventory = super::parse_input("5\n\n7\n\n3\n4\n\n7\n\n1");
+
+        let numbers =
+            |elves: Vec<&super::Elf>| elves.iter().map(|elf| elf.number).collect::<Vec<_>>();
+        assert_eq!(numbers(inventory.top(1)), vec![2]);
+        assert_eq!(numbers(inventory.top_with_ties(1)), vec![2, 3, 4]);
+        assert_eq!(numbers(inventory.top_with_ties(0)), Vec::<usize>::new());
+        assert_eq!(numbers(inventory.top_with_ties(9)).len(), 5);
+    }
+
+    #[test]
+    fn query_example1() {
+        use super::Query;
+
+        let inventory = super::parse_input("5\n\n7\n\n3\n4\n\n7\n\n1");
+        let query = |q| super::query(&inventory, q).unwrap();
+
+        assert_eq!(
+            query(Query::Top { k: 1, ties: true }),
+            "Top 1: elf 2 (7), elf 3 (7), elf 4 (7); 7 calories"
+        );
+        assert_eq!(
+            query(Query::Top { k: 2, ties: false }),
+            "Top 2: elf 2 (7), elf 3 (7); 14 calories"
+        );
+        assert_eq!(query(Query::Percentile(50.0)), "Percentile 50: 7");
+        assert_eq!(
+            query(Query::Histogram(5)),
+            "       0      1\n       5      4"
+        );
+        assert_eq!(
+            query(Query::Elf(4)),
+            "Elf 4: 7 calories in 1 items, rank 3 of 5"
+        );
+        assert!(super::query(&inventory, Query::Elf(6)).is_err());
+        assert!(super::query(&inventory, Query::Percentile(-1.0)).is_err());
+    }
+
+    /// `elves` elves of three items each, generated on the fly.
+    struct Generated {
+        elves: usize,
+        line: usize,
+        pending: Vec<u8>,
+    }
+
+    impl std::io::Read for Generated {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            while self.pending.is_empty() && self.line < self.elves * 4 {
+                let (elf, item) = (self.line / 4, self.line % 4);
+                self.pending = match item {
+                    3 => b"\n".to_vec(),
+                    _ => format!("{}\n", (elf * 7919 + item * 104729) % 100000).into_bytes(),
+                };
+                self.line += 1;
+            }
+
+            let n = buf.len().min(self.pending.len());
+            buf[..n].copy_from_slice(&self.pending[..n]);
+            self.pending.drain(..n);
+            return Ok(n);
+        }
+    }
+
+    #[test]
+    fn stream_matches_the_inventory() {
+        let generated = || Generated {
+            elves: 2000,
+            line: 0,
+            pending: Vec::new(),
+        };
+        let mut text = String::new();
+        std::io::Read::read_to_string(&mut generated(), &mut text).unwrap();
+        let inventory = super::parse_input(&text);
+
+        let top = super::stream_top(generated(), 5).unwrap();
+        let expected = inventory
+            .top(5)
+            .iter()
+            .map(|elf| (elf.number, elf.total()))
+            .collect::<Vec<_>>();
+        assert_eq!(top, expected);
+
+        let (part1, part2) = super::stream(EXAMPLE1.as_bytes(), 1, 3).unwrap();
+        assert_eq!((part1.as_str(), part2.as_str()), ("24000", "45000"));
+        assert_eq!(super::stream_top("\n\n".as_bytes(), 3).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn stream_reports_overflows() {
+        let input = "1\n\n4294967295\n1\n";
+        let error = super::stream_top(input.as_bytes(), 1).unwrap_err();
+
+        assert!(error.to_string().contains("elf 2"), "{}", error);
+        assert!(super::stream_top("12\nx\n".as_bytes(), 1).is_err());
+    }
+
+    #[test]
+    fn parse_reports_bad_items_and_overflows() {
+        assert!("1000\nlots".parse::<super::Inventory>().is_err());
+        assert!("4294967295\n1".parse::<super::Inventory>().is_err());
+    }
+
+    #[test]
+    fn parse_skips_extra_blank_lines() {
+        let inventory = "1\n\n\n2".parse::<super::Inventory>().unwrap();
+        let totals = inventory
+            .elves()
+            .iter()
+            .map(|elf| (elf.number, elf.total()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(totals, vec![(1, 1), (2, 2)]);
+        assert_eq!(
+            super::stream_top("1\n\n\n2".as_bytes(), 2).unwrap(),
+            vec![(2, 2), (1, 1)]
+        );
+    }
+}
@@ -0,0 +1,506 @@
+//! Day 2: Rock Paper Scissors.
+//!
+//! Each line of the strategy guide holds the elf's move (`A`, `B`, `C`) and a
+//! second column (`X`, `Y`, `Z`) whose meaning differs between the parts.
+
+use itertools::Itertools;
+
+use crate::error::Error;
+
+/// The moves of the games played by the runner; the `moves` parameter.
+pub const MOVES: usize = 3;
+
+/// One round of the strategy guide, both columns numbered from 0: `A`, `B`,
+/// `C`... for the elf and `X`, `Y`, `Z`... for the second column. Games of
+/// many moves may number them from 1 instead, e.g. `1 5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub elf: usize,
+    pub player: usize,
+}
+
+fn column(s: &str, first: char) -> Result<usize, Error> {
+    if let Ok(n) = s.parse::<usize>() {
+        return n
+            .checked_sub(1)
+            .ok_or_else(|| Error::ParseError(format!("moves are numbered from 1: {}", s)));
+    }
+
+    let mut chars = s.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(c), None) if c >= first && c.is_ascii_uppercase() => Ok(c as usize - first as usize),
+        _ => Err(Error::ParseError(format!("expected a move, found {:?}", s))),
+    };
+}
+
+impl std::str::FromStr for Play {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (elf, player) = s
+            .trim()
+            .split_once(' ')
+            .ok_or_else(|| Error::ParseError(s.to_owned()))?;
+
+        return Ok(Play {
+            elf: column(elf.trim(), 'A')?,
+            player: column(player.trim(), 'X')?,
+        });
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Play> {
+    crate::parsing::lines_to_vec::<Play>(input).unwrap()
+}
+
+/// How a round ends for the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    /// The outcome of the second column, `X` to lose, `Y` to draw and `Z` to
+    /// win.
+    pub fn from_column(column: usize) -> Result<Outcome, Error> {
+        return Outcome::ALL
+            .get(column)
+            .copied()
+            .ok_or_else(|| Error::ParseError(format!("there is no outcome {}", column)));
+    }
+}
+
+/// How the second column of the strategy guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    /// The move to play, as in part 1.
+    Move,
+    /// The outcome to reach, as in part 2.
+    Outcome,
+}
+
+/// A game of cyclic dominance: an odd number of moves in a circle, each one
+/// beating the half of the others that come before it and losing to the half
+/// that come after it. Rock, paper, scissors is the game of three moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    names: Vec<String>,
+    /// The score of playing each move.
+    moves: Vec<u32>,
+    /// The score of a loss, a draw and a win.
+    outcomes: [u32; 3],
+}
+
+impl Game {
+    /// The game of `n` moves, scored as in the puzzle: the number of the move
+    /// from 1, plus 0, 3 or 6 for a loss, a draw or a win.
+    pub fn new(n: usize) -> Result<Game, Error> {
+        let names = (1..=n).map(|i| i.to_string()).collect();
+        return Game::with_moves(names);
+    }
+
+    /// The game of the moves of `names`, in their cyclic order, scored as in
+    /// [`Game::new`].
+    pub fn with_moves(names: Vec<String>) -> Result<Game, Error> {
+        let n = names.len();
+        if n < 3 || n.is_multiple_of(2) {
+            return Err(Error::ConfigError(format!(
+                "a fair game needs an odd number of moves, at least 3, not {}",
+                n
+            )));
+        }
+
+        return Ok(Game {
+            names,
+            moves: (1..=n as u32).collect(),
+            outcomes: [0, 3, 6],
+        });
+    }
+
+    /// Replaces the scores of the moves and of a loss, a draw and a win.
+    pub fn with_scores(self: Self, moves: Vec<u32>, outcomes: [u32; 3]) -> Result<Game, Error> {
+        if moves.len() != self.names.len() {
+            return Err(Error::ConfigError(format!(
+                "expected {} move scores, found {}",
+                self.names.len(),
+                moves.len()
+            )));
+        }
+
+        return Ok(Game {
+            moves,
+            outcomes,
+            ..self
+        });
+    }
+
+    pub fn rock_paper_scissors() -> Game {
+        let names = ["rock", "paper", "scissors"].map(String::from).to_vec();
+        return Game::with_moves(names).expect("three moves to make a game");
+    }
+
+    /// Rock, paper, scissors, lizard, Spock, with the moves in their cyclic
+    /// order: each one beats the two before it.
+    pub fn rock_paper_scissors_lizard_spock() -> Game {
+        let names = ["rock", "Spock", "paper", "lizard", "scissors"]
+            .map(String::from)
+            .to_vec();
+        return Game::with_moves(names).expect("five moves to make a game");
+    }
+
+    pub fn len(self: &Self) -> usize {
+        return self.names.len();
+    }
+
+    /// The move called `name`.
+    pub fn find(self: &Self, name: &str) -> Option<usize> {
+        return self.names.iter().position(|n| n == name);
+    }
+
+    pub fn name(self: &Self, m: usize) -> &str {
+        return &self.names[m];
+    }
+
+    /// How a round of `mine` against `theirs` ends for me.
+    pub fn outcome(self: &Self, mine: usize, theirs: usize) -> Outcome {
+        let n = self.len();
+        let ahead = (mine + n - theirs) % n;
+
+        return match ahead {
+            0 => Outcome::Draw,
+            _ if ahead <= n / 2 => Outcome::Win,
+            _ => Outcome::Loss,
+        };
+    }
+
+    pub fn beats(self: &Self, a: usize, b: usize) -> bool {
+        return self.outcome(a, b) == Outcome::Win;
+    }
+
+    /// A move that reaches `outcome` against `theirs`: the one right after it
+    /// to win and the one right before it to lose.
+    pub fn response(self: &Self, theirs: usize, outcome: Outcome) -> usize {
+        let n = self.len();
+
+        return match outcome {
+            Outcome::Draw => theirs,
+            Outcome::Win => (theirs + 1) % n,
+            Outcome::Loss => (theirs + n - 1) % n,
+        };
+    }
+
+    pub fn score(self: &Self, mine: usize, theirs: usize) -> u32 {
+        return self.moves[mine] + self.outcomes[self.outcome(mine, theirs) as usize];
+    }
+
+    /// My move in `play`, when its second column is read as `column`.
+    pub fn my_move(self: &Self, play: &Play, column: Column) -> Result<usize, Error> {
+        let check = |m: usize| match m < self.len() {
+            true => Ok(m),
+            false => Err(Error::ParseError(format!(
+                "there is no move {} in a game of {}",
+                m + 1,
+                self.len()
+            ))),
+        };
+
+        let theirs = check(play.elf)?;
+        return match column {
+            Column::Move => check(play.player),
+            Column::Outcome => Ok(self.response(theirs, Outcome::from_column(play.player)?)),
+        };
+    }
+
+    /// My score for the rounds of `plays`, which may be many more than a
+    /// `u32` of points.
+    pub fn total(self: &Self, plays: &[Play], column: Column) -> Result<u64, Error> {
+        return plays
+            .iter()
+            .map(|play| Ok(self.score(self.my_move(play, column)?, play.elf) as u64))
+            .sum();
+    }
+}
+
+/// A reading of the second column of the strategy guide, which the elf left
+/// unexplained: the meaning of each of its letters, `X` first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mapping {
+    Moves(Vec<usize>),
+    Outcomes(Vec<Outcome>),
+}
+
+impl Mapping {
+    /// The reading of `column` for a guide whose second column has `letters`
+    /// letters.
+    pub fn of(column: Column, letters: usize) -> Mapping {
+        return match column {
+            Column::Move => Mapping::Moves((0..letters).collect()),
+            Column::Outcome => Mapping::Outcomes(Outcome::ALL[..letters.min(3)].to_vec()),
+        };
+    }
+
+    /// My move against `theirs` when the second column is `letter`.
+    fn my_move(self: &Self, game: &Game, theirs: usize, letter: usize) -> usize {
+        return match self {
+            Mapping::Moves(moves) => moves[letter],
+            Mapping::Outcomes(outcomes) => game.response(theirs, outcomes[letter]),
+        };
+    }
+
+    pub fn describe(self: &Self, game: &Game) -> String {
+        let meanings: Vec<String> = match self {
+            Mapping::Moves(moves) => moves.iter().map(|&m| game.name(m).to_string()).collect(),
+            Mapping::Outcomes(outcomes) => outcomes
+                .iter()
+                .map(|o| format!("{:?}", o).to_lowercase())
+                .collect(),
+        };
+
+        return meanings
+            .iter()
+            .enumerate()
+            .map(|(letter, meaning)| format!("{}={}", letter_name(letter), meaning))
+            .collect::<Vec<_>>()
+            .join(" ");
+    }
+}
+
+/// The name of the `letter`th letter of the second column: `X`, `Y`, `Z`,
+/// then numbers as for games of many moves.
+fn letter_name(letter: usize) -> String {
+    return match letter {
+        0..=2 => ((b'X' + letter as u8) as char).to_string(),
+        _ => (letter + 1).to_string(),
+    };
+}
+
+/// A reading of the strategy guide and the score it gives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decryption {
+    pub mapping: Mapping,
+    pub score: u64,
+}
+
+/// The most readings [`decrypt`] goes through: all those of a game of 9
+/// moves, about a second of work.
+pub const MAX_MAPPINGS: usize = 362_880;
+
+/// Every reading of the second column of `plays` in `game`, best first: each
+/// assignment of distinct moves to its letters, and of distinct outcomes when
+/// it has at most three. Readings of the same score keep the order of their
+/// mappings, the moves before the outcomes.
+pub fn decrypt(game: &Game, plays: &[Play]) -> Result<Vec<Decryption>, Error> {
+    let letters = plays.iter().map(|p| p.player + 1).max().unwrap_or(0);
+    if letters > game.len() {
+        return Err(Error::ParseError(format!(
+            "the second column has {} letters, more than the {} moves of the game",
+            letters,
+            game.len()
+        )));
+    }
+
+    // The assignments of distinct moves to the letters.
+    let mappings = (game.len() + 1 - letters..=game.len())
+        .try_fold(1usize, |product, n| product.checked_mul(n))
+        .filter(|&mappings| mappings <= MAX_MAPPINGS)
+        .ok_or_else(|| {
+            Error::ConfigError(format!(
+                "{} letters of a game of {} moves have more than {} readings",
+                letters,
+                game.len(),
+                MAX_MAPPINGS
+            ))
+        })?;
+    crate::debug!("{} readings of the moves to score", mappings);
+
+    // How often each pair of columns is played, so that each mapping is
+    // scored without going through the guide again.
+    let mut rounds = vec![vec![0u64; letters]; game.len()];
+    for play in plays {
+        if play.elf >= game.len() {
+            return Err(Error::ParseError(format!(
+                "there is no move {} in a game of {}",
+                play.elf + 1,
+                game.len()
+            )));
+        }
+        rounds[play.elf][play.player] += 1;
+    }
+
+    let moves = (0..game.len()).permutations(letters).map(Mapping::Moves);
+    let outcomes = Outcome::ALL
+        .into_iter()
+        .permutations(letters)
+        .filter(|_| letters <= 3)
+        .map(Mapping::Outcomes);
+
+    let mut ranking: Vec<Decryption> = moves
+        .chain(outcomes)
+        .map(|mapping| {
+            let mut score = 0;
+            for (theirs, counts) in rounds.iter().enumerate() {
+                for (letter, &count) in counts.iter().enumerate() {
+                    let points = game.score(mapping.my_move(game, theirs, letter), theirs);
+                    score += count * points as u64;
+                }
+            }
+            Decryption { mapping, score }
+        })
+        .collect();
+
+    ranking.sort_by_key(|d| std::cmp::Reverse(d.score));
+    return Ok(ranking);
+}
+
+/// The first reading of `ranking` whose score is the closest to `target`.
+pub fn closest(ranking: &[Decryption], target: u64) -> Option<&Decryption> {
+    return ranking.iter().min_by_key(|d| d.score.abs_diff(target));
+}
+
+/// The total score of a game of `moves` moves when the second column is the
+/// player's move.
+pub fn part1(input: &Vec<Play>, moves: usize) -> String {
+    let game = Game::new(moves).unwrap();
+    return game.total(input, Column::Move).unwrap().to_string();
+}
+
+/// The total score of a game of `moves` moves when the second column is the
+/// desired outcome.
+pub fn part2(input: &Vec<Play>, moves: usize) -> String {
+    let game = Game::new(moves).unwrap();
+    return game.total(input, Column::Outcome).unwrap().to_string();
+}
+
+/// Day 2 for the runner, see [`crate::solution::Solution`].
+pub struct Day02;
+
+impl crate::solution::Solution for Day02 {
+    type Input<'a> = Vec<Play>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 2;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("moves", MOVES))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("moves", MOVES))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "A Y
+B X
+C Z",
+        );
+
+        assert_eq!(super::part1(&input, super::MOVES), "15");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "A Y
+B X
+C Z",
+        );
+
+        assert_eq!(super::part2(&input, super::MOVES), "12");
+    }
+
+    #[test]
+    fn games_of_more_moves() {
+        use super::{Game, Outcome};
+
+        let rpsls = Game::rock_paper_scissors_lizard_spock();
+        let m = |name| rpsls.find(name).unwrap();
+        for (a, b) in [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "Spock"),
+            ("Spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "Spock"),
+            ("Spock", "rock"),
+            ("rock", "scissors"),
+        ] {
+            assert!(rpsls.beats(m(a), m(b)), "{} beats {}", a, b);
+            assert_eq!(rpsls.outcome(m(b), m(a)), Outcome::Loss);
+        }
+
+        let game = Game::new(7).unwrap();
+        for theirs in 0..7 {
+            for outcome in [Outcome::Loss, Outcome::Draw, Outcome::Win] {
+                assert_eq!(
+                    game.outcome(game.response(theirs, outcome), theirs),
+                    outcome
+                );
+            }
+            let wins = (0..7).filter(|&m| game.beats(m, theirs)).count();
+            assert_eq!(wins, 3);
+        }
+
+        let input = super::parse_input("1 7\n5 3\nC X");
+        assert_eq!(game.total(&input, super::Column::Move).unwrap(), 7 + 3 + 1);
+        let input = super::parse_input("A X\nB Y\nG Z");
+        assert_eq!(super::part2(&input, 7), "19");
+        assert!(game
+            .total(&super::parse_input("8 1"), super::Column::Move)
+            .is_err());
+        assert!(Game::new(4).is_err());
+    }
+
+    #[test]
+    fn decrypt_example1() {
+        use super::{Column, Game, Mapping};
+
+        let game = Game::rock_paper_scissors();
+        let input = super::parse_input("A Y\nB X\nC Z");
+        let ranking = super::decrypt(&game, &input).unwrap();
+
+        assert_eq!(ranking.len(), 12);
+        let best = &ranking[0];
+        assert_eq!(best.score, 24);
+        assert_eq!(best.mapping.describe(&game), "X=scissors Y=paper Z=rock");
+        assert!(ranking.windows(2).all(|w| w[0].score >= w[1].score));
+
+        let score = |column| {
+            let mapping = Mapping::of(column, 3);
+            ranking.iter().find(|d| d.mapping == mapping).unwrap().score
+        };
+        assert_eq!(score(Column::Move), 15);
+        assert_eq!(score(Column::Outcome), 12);
+
+        assert_eq!(super::closest(&ranking, 13).unwrap().score, 12);
+        let outcomes = super::closest(&ranking, 12).unwrap();
+        assert_eq!(outcomes.mapping.describe(&game), "X=loss Y=draw Z=win");
+
+        let many = super::parse_input("A 4");
+        assert!(super::decrypt(&game, &many).is_err());
+
+        let wide = Game::new(15).unwrap();
+        let numbered = super::parse_input("1 15\n2 14\n3 13\n4 12\n5 11\n6 10\n7 9");
+        assert!(super::decrypt(&wide, &numbered).is_err());
+        assert_eq!(
+            super::decrypt(&wide, &super::parse_input("1 3"))
+                .unwrap()
+                .len(),
+            15 * 14 * 13 + 6
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! Day 3: Rucksack Reorganization.
+//!
+//! Every line is a rucksack whose items are letters; the first and second half
+//! of the line are its two compartments.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::collections::BitSet64;
+use crate::error::Error;
+
+/// The compartments of a rucksack in part 1; the `compartments` parameter.
+pub const COMPARTMENTS: usize = 2;
+/// The rucksacks of a group in part 2; the `group` parameter.
+pub const GROUP: usize = 3;
+
+/// The priority of an item: `a` to `z` are 1 to 26, `A` to `Z` are 27 to 52.
+pub fn item_to_value(c: char) -> usize {
+    if c.is_ascii_uppercase() {
+        return c as usize - 'A' as usize + 1 + 26;
+    } else {
+        return c as usize - 'a' as usize + 1;
+    }
+}
+
+/// The item of priority `value`, the inverse of [`item_to_value`].
+pub fn value_to_item(value: usize) -> char {
+    return match value {
+        1..=26 => (b'a' + value as u8 - 1) as char,
+        _ => (b'A' + value as u8 - 27) as char,
+    };
+}
+
+fn parse_rucksack(line: &str) -> Result<Vec<usize>, Error> {
+    return line
+        .chars()
+        .map(|c| match c.is_ascii_alphabetic() {
+            true => Ok(item_to_value(c)),
+            false => Err(Error::ParseError(format!(
+                "{:?} is not an item: {}",
+                c, line
+            ))),
+        })
+        .collect();
+}
+
+/// Returns the priorities of the items of every rucksack.
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Vec<usize>> {
+    input
+        .as_ref()
+        .lines()
+        .map(parse_rucksack)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+/// The items of every one of `sets`: the priorities of the items, as bits.
+pub fn shared(sets: impl IntoIterator<Item = BitSet64>) -> BitSet64 {
+    return sets.into_iter().fold(
+        BitSet64::full(53).difference(BitSet64::from_bits(1)),
+        |a, b| a.intersection(b),
+    );
+}
+
+/// The items shared by some rucksacks, or by the compartments of one; the
+/// puzzle expects exactly one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    /// The number of the group, or of the rucksack, from 1.
+    pub number: usize,
+    /// The rucksacks involved, as indices of the input.
+    pub rucksacks: Range<usize>,
+    pub items: BitSet64,
+}
+
+impl Share {
+    /// The shared item, when there is exactly one.
+    pub fn item(self: &Self) -> Option<usize> {
+        return match self.items.len() {
+            1 => self.items.iter().next(),
+            _ => None,
+        };
+    }
+}
+
+impl fmt::Display for Share {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rucksacks = match self.rucksacks.len() {
+            1 => format!("rucksack {}", self.rucksacks.start + 1),
+            _ => format!(
+                "rucksacks {}-{}",
+                self.rucksacks.start + 1,
+                self.rucksacks.end
+            ),
+        };
+        let items: String = self.items.iter().map(value_to_item).collect();
+
+        match self.items.len() {
+            0 => write!(f, "#{} ({}): nothing shared", self.number, rucksacks),
+            1 => write!(f, "#{} ({}): {}", self.number, rucksacks, items),
+            n => write!(
+                f,
+                "#{} ({}): {} items shared, {}",
+                self.number, rucksacks, n, items
+            ),
+        }
+    }
+}
+
+/// The shares of a whole list, with those that are not a single item apart.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub shares: Vec<Share>,
+}
+
+impl Analysis {
+    /// The items shared by the `compartments` equal parts of every rucksack.
+    pub fn compartments(input: &[Vec<usize>], compartments: usize) -> Result<Analysis, Error> {
+        if compartments == 0 {
+            return Err(Error::ConfigError(
+                "a rucksack has at least one compartment".to_string(),
+            ));
+        }
+
+        let shares = input
+            .iter()
+            .enumerate()
+            .map(|(i, rucksack)| {
+                if !rucksack.len().is_multiple_of(compartments) {
+                    return Err(Error::ParseError(format!(
+                        "rucksack {} of {} items does not split into {} compartments",
+                        i + 1,
+                        rucksack.len(),
+                        compartments
+                    )));
+                }
+
+                let size = rucksack.len() / compartments;
+                let parts = rucksack
+                    .chunks(size.max(1))
+                    .map(|part| part.iter().copied().collect::<BitSet64>());
+
+                Ok(Share {
+                    number: i + 1,
+                    rucksacks: i..i + 1,
+                    items: match size {
+                        0 => BitSet64::new(),
+                        _ => shared(parts),
+                    },
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Analysis { shares });
+    }
+
+    /// The items shared by every rucksack of each group of `size`.
+    pub fn groups(input: &[Vec<usize>], size: usize) -> Result<Analysis, Error> {
+        if size == 0 || !input.len().is_multiple_of(size) {
+            return Err(Error::ParseError(format!(
+                "{} rucksacks do not make groups of {}",
+                input.len(),
+                size
+            )));
+        }
+
+        let shares = input
+            .chunks(size)
+            .enumerate()
+            .map(|(i, group)| Share {
+                number: i + 1,
+                rucksacks: i * size..(i + 1) * size,
+                items: shared(
+                    group
+                        .iter()
+                        .map(|rucksack| rucksack.iter().copied().collect()),
+                ),
+            })
+            .collect();
+
+        return Ok(Analysis { shares });
+    }
+
+    /// The shares of no item or of several.
+    pub fn anomalies(self: &Self) -> impl Iterator<Item = &Share> {
+        return self.shares.iter().filter(|share| share.item().is_none());
+    }
+
+    /// The sum of the priorities of the shared items, leaving out the
+    /// anomalies.
+    pub fn sum(self: &Self) -> usize {
+        return self.shares.iter().filter_map(Share::item).sum();
+    }
+
+    /// Logs every share at the debug level and the anomalies as warnings.
+    fn log(self: &Self, what: &str) {
+        crate::debug!("{}:\n{}", what, self);
+        for share in self.anomalies() {
+            crate::warn!("{} {}", what, share);
+        }
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for share in &self.shares {
+            writeln!(f, "{}", share)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The sum of the priorities of the items found in every compartment.
+pub fn part1(input: &Vec<Vec<usize>>, compartments: usize) -> String {
+    let analysis = Analysis::compartments(input, compartments).unwrap();
+    analysis.log("rucksack");
+
+    return analysis.sum().to_string();
+}
+
+/// The sum of the priorities of the badges shared by each group.
+pub fn part2(input: &Vec<Vec<usize>>, group: usize) -> String {
+    let analysis = Analysis::groups(input, group).unwrap();
+    analysis.log("group");
+
+    return analysis.sum().to_string();
+}
+
+/// Day 3 for the runner, see [`crate::solution::Solution`].
+pub struct Day03;
+
+impl crate::solution::Solution for Day03 {
+    type Input<'a> = Vec<Vec<usize>>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 3;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("compartments", COMPARTMENTS))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("group", GROUP))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+        );
+
+        assert_eq!(super::part1(&input, super::COMPARTMENTS), "157");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw",
+        );
+
+        assert_eq!(super::part2(&input, super::GROUP), "70");
+    }
+
+    #[test]
+    fn analysis_reports_anomalies() {
+        use super::Analysis;
+
+        let input = super::parse_input("abcXabcZ\nabcdabcd\nxyzXaaaX\naabbccdd\nazbycx");
+
+        let halves = Analysis::compartments(&input[..4], 2).unwrap();
+        let anomalies: Vec<String> = halves.anomalies().map(|s| s.to_string()).collect();
+        assert_eq!(
+            anomalies,
+            vec![
+                "#1 (rucksack 1): 3 items shared, abc",
+                "#2 (rucksack 2): 4 items shared, abcd",
+                "#4 (rucksack 4): nothing shared",
+            ]
+        );
+        assert_eq!(halves.sum(), super::item_to_value('X'));
+
+        let thirds = Analysis::compartments(&input[4..], 3).unwrap();
+        assert_eq!(
+            thirds.shares[0].to_string(),
+            "#1 (rucksack 1): nothing shared"
+        );
+        assert!(Analysis::compartments(&input, 3).is_err());
+
+        let pairs = Analysis::groups(&input[..4], 2).unwrap();
+        assert_eq!(pairs.shares[1].to_string(), "#2 (rucksacks 3-4): a");
+        assert_eq!(pairs.sum(), 1);
+        assert!(Analysis::groups(&input, 2).is_err());
+        assert_eq!(super::value_to_item(52), 'Z');
+    }
+}
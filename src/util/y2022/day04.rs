@@ -0,0 +1,393 @@
+//! Day 4: Camp Cleanup.
+//!
+//! Every line holds the section ranges assigned to a pair of elves, such as
+//! `2-4,6-8`.
+
+use std::fmt;
+use std::str::FromStr;
+
+/// An inclusive range of section ids, which parses only when `low <= high <
+/// usize::MAX`, so that its length and the section after it fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    pub low: usize,
+    pub high: usize,
+}
+
+impl FromStr for Range {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (low, high) = s
+            .split_once("-")
+            .ok_or_else(|| Self::Err::ParseError(s.to_owned()))?;
+
+        let range = Range {
+            low: low
+                .parse()
+                .map_err(|_| Self::Err::ParseError(s.to_owned()))?,
+            high: high
+                .parse()
+                .map_err(|_| Self::Err::ParseError(s.to_owned()))?,
+        };
+        if range.low > range.high {
+            return Err(Self::Err::ParseError(format!("empty range {}", s)));
+        }
+        if range.high.checked_add(1).is_none() {
+            return Err(Self::Err::ParseError(format!("section too large in {}", s)));
+        }
+
+        return Ok(range);
+    }
+}
+
+impl Range {
+    pub fn len(self: &Self) -> usize {
+        return self.high - self.low + 1;
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.low == self.high {
+            true => write!(f, "{}", self.low),
+            false => write!(f, "{}-{}", self.low, self.high),
+        }
+    }
+}
+
+/// The assignments of the two elves of one line.
+#[derive(Debug)]
+pub struct Pair {
+    pub r1: Range,
+    pub r2: Range,
+}
+
+impl FromStr for Pair {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (r1, r2) = s
+            .split_once(",")
+            .ok_or_else(|| Self::Err::ParseError(s.to_owned()))?;
+
+        return Ok(Pair {
+            r1: r1.parse()?,
+            r2: r2.parse()?,
+        });
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Pair> {
+    crate::parsing::lines_to_vec(input).unwrap()
+}
+
+/// The number of pairs where one range fully contains the other.
+pub fn part1(pairs: &Vec<Pair>) -> String {
+    pairs
+        .iter()
+        .filter(|pair| {
+            (pair.r1.low <= pair.r2.low && pair.r2.high <= pair.r1.high)
+                || (pair.r2.low <= pair.r1.low && pair.r1.high <= pair.r2.high)
+        })
+        .count()
+        .to_string()
+}
+
+/// The number of pairs whose ranges overlap.
+pub fn part2(pairs: &Vec<Pair>) -> String {
+    pairs
+        .iter()
+        .filter(|pair| pair.r1.low <= pair.r2.high && pair.r2.low <= pair.r1.high)
+        .count()
+        .to_string()
+}
+
+/// One elf of the input: the first or second of a line, both from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub line: usize,
+    pub elf: usize,
+    pub range: Range,
+}
+
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} elf {} ({})", self.line, self.elf, self.range)
+    }
+}
+
+/// Every elf of `pairs`, in the order of the input.
+pub fn elves(pairs: &[Pair]) -> Vec<Elf> {
+    return (1..)
+        .zip(pairs)
+        .flat_map(|(line, pair)| {
+            [(1, pair.r1), (2, pair.r2)].map(|(elf, range)| Elf { line, elf, range })
+        })
+        .collect();
+}
+
+/// Sections covered by the same number of elves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub range: Range,
+    pub elves: usize,
+}
+
+/// How many elves cover each section, from the lowest section assigned to the
+/// highest, found by sweeping over the ends of the ranges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// The runs of sections with the same number of elves, in order; those of
+    /// no elf included.
+    pub segments: Vec<Segment>,
+}
+
+impl Coverage {
+    pub fn new(elves: &[Elf]) -> Coverage {
+        // The elves entering the sweep at the first section of their range
+        // and leaving it after the last, which a parsed `Range` has room for.
+        let mut events: Vec<(usize, isize)> = elves
+            .iter()
+            .flat_map(|elf| [(elf.range.low, 1), (elf.range.high + 1, -1)])
+            .collect();
+        events.sort_unstable();
+
+        let mut segments: Vec<Segment> = Vec::new();
+        let mut count = 0;
+        let mut i = 0;
+        while i < events.len() {
+            let low = events[i].0;
+            while i < events.len() && events[i].0 == low {
+                count += events[i].1;
+                i += 1;
+            }
+            let Some(&(next, _)) = events.get(i) else {
+                break;
+            };
+
+            let elves = count as usize;
+            match segments.last_mut() {
+                Some(last) if last.elves == elves => last.range.high = next - 1,
+                _ => segments.push(Segment {
+                    range: Range {
+                        low,
+                        high: next - 1,
+                    },
+                    elves,
+                }),
+            }
+        }
+
+        return Coverage { segments };
+    }
+
+    /// The largest number of elves on one section, and the sections with
+    /// that many.
+    pub fn most(self: &Self) -> (usize, Vec<Range>) {
+        let most = self.segments.iter().map(|s| s.elves).max().unwrap_or(0);
+        let ranges = self
+            .segments
+            .iter()
+            .filter(|s| s.elves == most && most > 0)
+            .map(|s| s.range)
+            .collect();
+
+        return (most, ranges);
+    }
+
+    /// The sections between the lowest and the highest assigned that no elf
+    /// covers.
+    pub fn uncovered(self: &Self) -> Vec<Range> {
+        return self
+            .segments
+            .iter()
+            .filter(|s| s.elves == 0)
+            .map(|s| s.range)
+            .collect();
+    }
+
+    /// The number of sections of `range` that more than one elf covers.
+    pub fn shared(self: &Self, range: &Range) -> usize {
+        let first = self.segments.partition_point(|s| s.range.high < range.low);
+
+        return self.segments[first..]
+            .iter()
+            .take_while(|s| s.range.low <= range.high)
+            .filter(|s| s.elves > 1)
+            .map(|s| s.range.high.min(range.high) + 1 - s.range.low.max(range.low))
+            .sum();
+    }
+}
+
+/// The pairs of elves of different lines whose ranges overlap, as indices of
+/// `elves` with the lower first, found by sweeping over the ranges by their
+/// first section.
+pub fn overlaps(elves: &[Elf]) -> Vec<(usize, usize)> {
+    let mut order: Vec<usize> = (0..elves.len()).collect();
+    order.sort_by_key(|&i| elves[i].range.low);
+
+    let mut overlaps = Vec::new();
+    let mut active: Vec<usize> = Vec::new();
+    for i in order {
+        let elf = &elves[i];
+        active.retain(|&j| elves[j].range.high >= elf.range.low);
+
+        for &j in &active {
+            if elves[j].line != elf.line {
+                overlaps.push((i.min(j), i.max(j)));
+            }
+        }
+        active.push(i);
+    }
+
+    overlaps.sort_unstable();
+    return overlaps;
+}
+
+/// What `day04 --query` reports on the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Query {
+    /// The sections covered by the most elves
+    Most,
+    /// The pairs of elves of different lines whose ranges overlap
+    Overlaps,
+    /// The sections no elf covers
+    Uncovered,
+    /// The sections of every elf that another one covers too
+    Redundancy,
+}
+
+fn ranges(ranges: &[Range]) -> String {
+    return match ranges.is_empty() {
+        true => "none".to_string(),
+        false => ranges
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
+}
+
+/// The answer to `query` about the elves of `pairs`, one line per finding.
+pub fn query(pairs: &[Pair], query: Query) -> String {
+    let elves = elves(pairs);
+    let coverage = Coverage::new(&elves);
+
+    return match query {
+        Query::Most => {
+            let (most, sections) = coverage.most();
+            format!("{} elves on {}", most, ranges(&sections))
+        }
+        Query::Overlaps => overlaps(&elves)
+            .into_iter()
+            .map(|(i, j)| format!("{} and {}", elves[i], elves[j]))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Query::Uncovered => ranges(&coverage.uncovered()),
+        Query::Redundancy => elves
+            .iter()
+            .map(|elf| {
+                let shared = coverage.shared(&elf.range);
+                let len = elf.range.len();
+                format!(
+                    "{}: {} of {} sections shared, {:.0}%",
+                    elf,
+                    shared,
+                    len,
+                    100.0 * shared as f64 / len as f64
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+}
+
+/// Day 4 for the runner, see [`crate::solution::Solution`].
+pub struct Day04;
+
+impl crate::solution::Solution for Day04 {
+    type Input<'a> = Vec<Pair>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 4;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8",
+        );
+
+        assert_eq!(super::part1(&input), "2");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8",
+        );
+
+        assert_eq!(super::part2(&input), "4");
+    }
+
+    #[test]
+    fn query_example1() {
+        use super::Query;
+
+        let input = super::parse_input(
+            "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+12-13,15-15",
+        );
+
+        assert_eq!(super::query(&input, Query::Most), "8 elves on 6");
+        assert_eq!(super::query(&input, Query::Uncovered), "10-11, 14");
+
+        let overlaps = super::query(&input, Query::Overlaps);
+        assert!(overlaps.starts_with("line 1 elf 1 (2-4) and line 2 elf 1 (2-3)\n"));
+        assert!(!overlaps.contains("line 7"));
+        assert_eq!(overlaps.lines().count(), 45);
+
+        let redundancy = super::query(&input, Query::Redundancy);
+        assert!(redundancy.contains("line 3 elf 2 (7-9): 2 of 3 sections shared, 67%"));
+        assert!(redundancy.ends_with("line 7 elf 2 (15): 0 of 1 sections shared, 0%"));
+        assert!("5-4,1-2".parse::<super::Pair>().is_err());
+
+        let max = usize::MAX;
+        assert!(format!("1-{},1-2", max).parse::<super::Pair>().is_err());
+        let input = super::parse_input(format!("0-{},{}-{}", max - 1, max - 1, max - 1));
+        assert_eq!(
+            super::query(&input, Query::Most),
+            format!("2 elves on {}", max - 1)
+        );
+    }
+}
@@ -0,0 +1,750 @@
+//! Day 5: Supply Stacks.
+//!
+//! The input is a drawing of the stacks of crates followed by the moves of the
+//! crane. Stacks are stored bottom first, so that their top is the end of a
+//! `Vec`, and numbered from 0.
+
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// The crane of part 1; the `crane_1` parameter, see [`crane`].
+pub const CRANE_1: &str = "9000";
+/// The crane of part 2; the `crane_2` parameter.
+pub const CRANE_2: &str = "9001";
+
+/// The stacks of crates, bottom first; a crate is its label, a letter in the
+/// puzzle.
+pub type Stacks = Vec<Vec<String>>;
+
+/// `move <count> from <from> to <to>`, with the stacks numbered from 0.
+#[derive(Debug)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl FromStr for Move {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(" ");
+
+        parts.next();
+        let count = parts
+            .next()
+            .ok_or(Self::Err::ParseError("failed to find count".to_string()))?;
+        let count = count
+            .parse::<usize>()
+            .map_err(|_| Self::Err::ParseError(format!("failed to parse count {}", count)))?;
+
+        parts.next();
+        let from = parts
+            .next()
+            .ok_or(Self::Err::ParseError("failed to find from".to_string()))?;
+        let from = from
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .ok_or_else(|| Self::Err::ParseError(format!("failed to parse from {}", from)))?;
+
+        parts.next();
+        let to = parts
+            .next()
+            .ok_or(Self::Err::ParseError("failed to find to".to_string()))?;
+        let to = to
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .ok_or_else(|| Self::Err::ParseError(format!("failed to parse to {}", to)))?;
+
+        return Ok(Move { count, from, to });
+    }
+}
+
+impl std::fmt::Display for Move {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+/// A model of crane: how it carries out a move, changing the stacks in place.
+pub trait Crane {
+    /// The name of the model, as given to [`crane`].
+    fn name(self: &Self) -> String;
+
+    /// Carries out `m`, which [`check`] found possible.
+    fn lift(self: &Self, stacks: &mut Stacks, m: &Move);
+
+    /// Carries out `m`, or leaves the stacks alone when it is not possible.
+    fn apply(self: &Self, stacks: &mut Stacks, m: &Move) -> Result<(), Error> {
+        check(stacks, m)?;
+        self.lift(stacks, m);
+
+        return Ok(());
+    }
+}
+
+/// Whether the stacks of `m` exist and the first one has enough crates.
+pub fn check(stacks: &Stacks, m: &Move) -> Result<(), Error> {
+    let held = stacks.get(m.from).map_or(0, Vec::len);
+    return check_counts(stacks.len(), held, m).map_err(Error::ParseError);
+}
+
+/// [`check`] for `stacks` stacks, the first of `m` holding `held` crates.
+fn check_counts(stacks: usize, held: usize, m: &Move) -> Result<(), String> {
+    if m.from >= stacks || m.to >= stacks {
+        return Err(format!(
+            "move from {} to {} with {} stacks",
+            m.from + 1,
+            m.to + 1,
+            stacks
+        ));
+    }
+    if held < m.count {
+        return Err(format!(
+            "move of {} crates from stack {} of {}",
+            m.count,
+            m.from + 1,
+            held
+        ));
+    }
+
+    return Ok(());
+}
+
+/// The source and destination of `m`; `None` when they are the same stack,
+/// which the cranes that lift from the top leave as it was.
+fn ends<'a>(stacks: &'a mut Stacks, m: &Move) -> Option<[&'a mut Vec<String>; 2]> {
+    return stacks.get_disjoint_mut([m.from, m.to]).ok();
+}
+
+/// Moves the crates one at a time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9000;
+
+impl Crane for CrateMover9000 {
+    fn name(self: &Self) -> String {
+        return "9000".to_string();
+    }
+
+    fn lift(self: &Self, stacks: &mut Stacks, m: &Move) {
+        if let Some([from, to]) = ends(stacks, m) {
+            let at = from.len() - m.count;
+            to.extend(from.drain(at..).rev());
+        }
+    }
+}
+
+/// Moves the crates all at once, keeping their order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrateMover9001;
+
+impl Crane for CrateMover9001 {
+    fn name(self: &Self) -> String {
+        return "9001".to_string();
+    }
+
+    fn lift(self: &Self, stacks: &mut Stacks, m: &Move) {
+        if let Some([from, to]) = ends(stacks, m) {
+            let at = from.len() - m.count;
+            to.extend(from.drain(at..));
+        }
+    }
+}
+
+/// Moves at most `capacity` crates at a time, each load keeping its order;
+/// a capacity of 1 is the CrateMover 9000.
+#[derive(Debug, Clone, Copy)]
+pub struct Limited {
+    pub capacity: usize,
+}
+
+impl Crane for Limited {
+    fn name(self: &Self) -> String {
+        return format!("limited:{}", self.capacity);
+    }
+
+    fn lift(self: &Self, stacks: &mut Stacks, m: &Move) {
+        if let Some([from, to]) = ends(stacks, m) {
+            let mut left = m.count;
+            while left > 0 {
+                let load = left.min(self.capacity);
+                let at = from.len() - load;
+                to.extend(from.drain(at..));
+                left -= load;
+            }
+        }
+    }
+}
+
+/// Pulls the crates out from the bottom of the stack all at once and puts
+/// them on top of the other, keeping their order, which reorders a stack
+/// when it is both.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FromBottom;
+
+impl Crane for FromBottom {
+    fn name(self: &Self) -> String {
+        return "bottom".to_string();
+    }
+
+    fn lift(self: &Self, stacks: &mut Stacks, m: &Move) {
+        match ends(stacks, m) {
+            Some([from, to]) => to.extend(from.drain(..m.count)),
+            None => stacks[m.from].rotate_left(m.count),
+        }
+    }
+}
+
+/// The crane called `name`: `9000`, `9001`, `limited:N` or `bottom`.
+pub fn crane(name: &str) -> Result<Box<dyn Crane>, Error> {
+    if let Some(capacity) = name.strip_prefix("limited:") {
+        return match capacity.parse::<usize>() {
+            Ok(capacity) if capacity > 0 => Ok(Box::new(Limited { capacity })),
+            _ => Err(Error::ConfigError(format!("invalid capacity {}", capacity))),
+        };
+    }
+
+    return match name {
+        "9000" => Ok(Box::new(CrateMover9000)),
+        "9001" => Ok(Box::new(CrateMover9001)),
+        "bottom" => Ok(Box::new(FromBottom)),
+        _ => Err(Error::ConfigError(format!("unknown crane {}", name))),
+    };
+}
+
+/// `e`, said of the move of index `i`.
+fn at_move(i: usize, e: Error) -> Error {
+    return match e {
+        Error::ParseError(message) => Error::ParseError(format!("move {}: {}", i + 1, message)),
+        e => e,
+    };
+}
+
+/// Runs every move of `moves` with `crane`, stopping at the first one that is
+/// not possible.
+pub fn run(crane: &dyn Crane, stacks: &mut Stacks, moves: &[Move]) -> Result<(), Error> {
+    for (i, m) in moves.iter().enumerate() {
+        crane.apply(stacks, m).map_err(|e| at_move(i, e))?;
+    }
+
+    return Ok(());
+}
+
+/// The labels of the crates on top of the stacks, one after the other; empty
+/// stacks are skipped.
+pub fn tops(stacks: &Stacks) -> String {
+    return stacks
+        .iter()
+        .flat_map(|s| s.last().map(String::as_str))
+        .collect();
+}
+
+/// Draws `stacks` as in the puzzle, the crates as `[A]` over a base line
+/// numbering the stacks from 1; [`parse_stacks`] reads it back. Columns widen
+/// for longer labels and numbers.
+pub fn render_stacks(stacks: &Stacks) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let widths: Vec<usize> = stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| {
+            let label = stack.iter().map(|c| c.chars().count() + 2).max();
+            label.unwrap_or(0).max((i + 1).to_string().len()).max(3)
+        })
+        .collect();
+
+    let mut lines = Vec::new();
+    for row in (0..height).rev() {
+        let cells: Vec<String> = stacks
+            .iter()
+            .zip(&widths)
+            .map(|(stack, &width)| match stack.get(row) {
+                Some(c) => format!("{:^width$}", format!("[{}]", c)),
+                None => " ".repeat(width),
+            })
+            .collect();
+        lines.push(cells.join(" "));
+    }
+
+    let base: Vec<String> = widths
+        .iter()
+        .enumerate()
+        .map(|(i, &width)| format!("{:^width$}", i + 1))
+        .collect();
+    lines.push(base.join(" "));
+
+    return lines.join("\n");
+}
+
+/// A crate or a word of a line of the drawing, with the columns it spans.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Crate(String, std::ops::Range<usize>),
+    Word(String, std::ops::Range<usize>),
+}
+
+/// Splits a line of the drawing into crates, `[` to the next `]`, and words
+/// separated by blanks; columns count characters.
+fn tokenize(line: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+        if chars[i].is_whitespace() {
+            i += 1;
+        } else if chars[i] == '[' {
+            let end = chars[i..]
+                .iter()
+                .position(|&c| c == ']')
+                .map(|n| i + n)
+                .ok_or_else(|| format!("unclosed crate at column {}", start + 1))?;
+            let label: String = chars[start + 1..end].iter().collect();
+            if label.trim().is_empty() || label.contains('[') {
+                return Err(format!("invalid crate [{}] at column {}", label, start + 1));
+            }
+            i = end + 1;
+            tokens.push(Token::Crate(label, start..i));
+        } else {
+            while i < chars.len() && !chars[i].is_whitespace() && chars[i] != '[' {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect(), start..i));
+        }
+    }
+
+    return Ok(tokens);
+}
+
+/// Parses the drawing of the stacks, including its numbered base line, which
+/// gives the columns of the stacks: every crate must be above exactly one of
+/// its numbers. Lines may be of any length, stacks more than 9 and labels
+/// more than one character.
+pub fn parse_stacks(input: impl AsRef<str>) -> Result<Stacks, Error> {
+    let error = |line: usize, message: String| {
+        return Error::ParseError(format!("line {} of the drawing: {}", line + 1, message));
+    };
+
+    let lines: Vec<&str> = input.as_ref().lines().collect();
+    let Some(last) = lines.iter().rposition(|line| !line.trim().is_empty()) else {
+        return Ok(Vec::new());
+    };
+
+    // The columns of the stacks, from the numbers of the base line.
+    let mut columns = Vec::new();
+    for token in tokenize(lines[last]).map_err(|e| error(last, e))? {
+        match token {
+            Token::Word(word, span) if word.parse() == Ok(columns.len() + 1) => columns.push(span),
+            _ => {
+                return Err(error(
+                    last,
+                    format!(
+                        "expected stack {} in the numbered base line",
+                        columns.len() + 1
+                    ),
+                ))
+            }
+        }
+    }
+
+    let mut stacks: Stacks = vec![Vec::new(); columns.len()];
+    for (row, l) in (0..last).rev().enumerate() {
+        let mut filled = vec![false; columns.len()];
+
+        for token in tokenize(lines[l]).map_err(|e| error(l, e))? {
+            let (label, span) = match token {
+                Token::Crate(label, span) => (label, span),
+                Token::Word(word, _) => return Err(error(l, format!("{:?} is not a crate", word))),
+            };
+            let below: Vec<usize> = (0..columns.len())
+                .filter(|&s| columns[s].start < span.end && span.start < columns[s].end)
+                .collect();
+
+            let &[s] = below.as_slice() else {
+                return Err(error(
+                    l,
+                    format!("crate [{}] is not above exactly one stack", label),
+                ));
+            };
+            if filled[s] {
+                return Err(error(l, format!("two crates above stack {}", s + 1)));
+            }
+            if stacks[s].len() != row {
+                return Err(error(
+                    l,
+                    format!("crate [{}] floats above stack {}", label, s + 1),
+                ));
+            }
+
+            filled[s] = true;
+            stacks[s].push(label);
+        }
+    }
+
+    return Ok(stacks);
+}
+
+/// Checks that every move of `moves` is possible from `stacks` with any
+/// crane, as they all move the same number of crates: the stacks exist and
+/// hold enough crates. Reports every move that is not.
+pub fn validate(stacks: &Stacks, moves: &[Move]) -> Result<(), Error> {
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let mut mismatches = Vec::new();
+
+    for (i, m) in moves.iter().enumerate() {
+        let held = heights.get(m.from).copied().unwrap_or(0);
+        match check_counts(heights.len(), held, m) {
+            Ok(()) => {
+                heights[m.from] -= m.count;
+                heights[m.to] += m.count;
+            }
+            Err(e) => mismatches.push(format!("move {}: {}", i + 1, e)),
+        }
+    }
+
+    return match mismatches.is_empty() {
+        true => Ok(()),
+        false => Err(Error::ParseError(mismatches.join("; "))),
+    };
+}
+
+/// Splits the input into the starting stacks and the moves, without checking
+/// that the moves can be made; see [`parse`].
+pub fn parse_input(input: impl AsRef<str>) -> Result<(Stacks, Vec<Move>), Error> {
+    let input = input.as_ref();
+    let lines: Vec<&str> = input.lines().collect();
+    let Some(blank) = lines.iter().position(|line| line.trim().is_empty()) else {
+        return Err(Error::ParseError(
+            "expected a blank line between the drawing and the moves".to_string(),
+        ));
+    };
+
+    let stacks = parse_stacks(lines[..blank].join("\n"))?;
+    let moves = lines[blank + 1..]
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.parse::<Move>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    return Ok((stacks, moves));
+}
+
+/// Parses the drawing and the moves after it, checking that they agree.
+pub fn parse(input: impl AsRef<str>) -> Result<(Stacks, Vec<Move>), Error> {
+    let (stacks, moves) = parse_input(input)?;
+    validate(&stacks, &moves)?;
+
+    return Ok((stacks, moves));
+}
+
+/// Runs the moves one at a time with a crane, keeping the stacks after every
+/// move so that it can go back and forth between them.
+pub struct Simulator<'a> {
+    crane: &'a dyn Crane,
+    moves: &'a [Move],
+    /// The stacks after each of the moves run so far, the starting ones first.
+    history: Vec<Stacks>,
+    /// The moves done to reach the current stacks.
+    step: usize,
+}
+
+impl<'a> Simulator<'a> {
+    pub fn new(crane: &'a dyn Crane, stacks: Stacks, moves: &'a [Move]) -> Simulator<'a> {
+        return Simulator {
+            crane,
+            moves,
+            history: vec![stacks],
+            step: 0,
+        };
+    }
+
+    /// The number of moves done to reach the current stacks.
+    pub fn step(self: &Self) -> usize {
+        return self.step;
+    }
+
+    pub fn stacks(self: &Self) -> &Stacks {
+        return &self.history[self.step];
+    }
+
+    /// The move that led to the current stacks; `None` at the start.
+    pub fn last_move(self: &Self) -> Option<&Move> {
+        return self.step.checked_sub(1).map(|i| &self.moves[i]);
+    }
+
+    pub fn is_done(self: &Self) -> bool {
+        return self.step == self.moves.len();
+    }
+
+    /// Does the next move; false when they are all done.
+    pub fn forward(self: &mut Self) -> Result<bool, Error> {
+        if self.is_done() {
+            return Ok(false);
+        }
+
+        if self.step + 1 == self.history.len() {
+            let mut stacks = self.stacks().clone();
+            let m = &self.moves[self.step];
+            self.crane
+                .apply(&mut stacks, m)
+                .map_err(|e| at_move(self.step, e))?;
+            self.history.push(stacks);
+        }
+        self.step += 1;
+
+        return Ok(true);
+    }
+
+    /// Undoes the last move; false at the start.
+    pub fn back(self: &mut Self) -> bool {
+        if self.step == 0 {
+            return false;
+        }
+
+        self.step -= 1;
+        return true;
+    }
+
+    /// Goes to the stacks after the first `step` moves.
+    pub fn jump(self: &mut Self, step: usize) -> Result<(), Error> {
+        if step > self.moves.len() {
+            return Err(Error::ParseError(format!(
+                "there are only {} moves, not {}",
+                self.moves.len(),
+                step
+            )));
+        }
+
+        self.step = self.step.min(step);
+        while self.step < step {
+            self.forward()?;
+        }
+
+        return Ok(());
+    }
+
+    /// The drawing of the current stacks under a line saying how they were
+    /// reached.
+    pub fn render(self: &Self) -> String {
+        let header = match self.last_move() {
+            None => format!("start, crane {}", self.crane.name()),
+            Some(m) => format!("after {} of {}: {}", self.step, self.moves.len(), m),
+        };
+
+        return format!("{}\n{}", header, render_stacks(self.stacks()));
+    }
+}
+
+/// The top crates after `crane` ran every move on a copy of the stacks.
+fn solve(input: &(Stacks, Vec<Move>), crane: &dyn Crane) -> String {
+    let (stacks, moves) = input;
+    let mut stacks = stacks.clone();
+    run(crane, &mut stacks, moves).unwrap();
+
+    return tops(&stacks);
+}
+
+/// The top crates after `crane` ran every move: the CrateMover 9000 for the
+/// puzzle, or the crane of the `crane_1` parameter.
+pub fn part1(input: &(Stacks, Vec<Move>), crane: &dyn Crane) -> String {
+    return solve(input, crane);
+}
+
+/// The top crates after `crane` ran every move: the CrateMover 9001 for the
+/// puzzle, or the crane of the `crane_2` parameter.
+pub fn part2(input: &(Stacks, Vec<Move>), crane: &dyn Crane) -> String {
+    return solve(input, crane);
+}
+
+/// Day 5 for the runner, see [`crate::solution::Solution`].
+pub struct Day05;
+
+impl crate::solution::Solution for Day05 {
+    type Input<'a> = (Stacks, Vec<Move>);
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 5;
+    const HAS_RENDER: bool = true;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse(input).unwrap()
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        let crane = crane(&params.get("crane_1", CRANE_1.to_string())).unwrap();
+        part1(input, crane.as_ref())
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        let crane = crane(&params.get("crane_2", CRANE_2.to_string())).unwrap();
+        part2(input, crane.as_ref())
+    }
+
+    fn render(input: &Self::Input<'_>, params: &crate::config::Params) -> Option<String> {
+        let crane = crane(&params.get("crane_2", CRANE_2.to_string())).ok()?;
+        let (stacks, moves) = input;
+        let mut simulator = Simulator::new(crane.as_ref(), stacks.clone(), moves);
+        simulator.jump(moves.len()).ok()?;
+
+        Some(render_stacks(simulator.stacks()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2").unwrap();
+
+        assert_eq!(super::part1(&input, &super::CrateMover9000), "CMZ");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2").unwrap();
+
+        assert_eq!(super::part2(&input, &super::CrateMover9001), "MCD");
+    }
+
+    #[test]
+    fn cranes_example1() {
+        use super::{crane, Move};
+
+        let (stacks, moves) = super::parse_input("    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2").unwrap();
+        let labels = |labels: &[&str]| labels.iter().map(|l| l.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            stacks,
+            vec![
+                labels(&["Z", "N"]),
+                labels(&["M", "C", "D"]),
+                labels(&["P"])
+            ]
+        );
+
+        let tops = |name: &str| {
+            let mut stacks = stacks.clone();
+            super::run(crane(name).unwrap().as_ref(), &mut stacks, &moves).unwrap();
+            super::tops(&stacks)
+        };
+        assert_eq!(tops("limited:1"), "CMZ");
+        assert_eq!(tops("limited:3"), "MCD");
+        assert_eq!(tops("limited:2"), "MCZ");
+        assert_eq!(tops("bottom"), "DCM");
+
+        let mut few = stacks.clone();
+        let m = Move {
+            count: 4,
+            from: 0,
+            to: 1,
+        };
+        assert!(crane("9001").unwrap().apply(&mut few, &m).is_err());
+        assert_eq!(few, stacks);
+        assert!(crane("limited:0").is_err());
+
+        let m = Move {
+            count: 2,
+            from: 1,
+            to: 1,
+        };
+        let mut same = stacks.clone();
+        crane("9001").unwrap().apply(&mut same, &m).unwrap();
+        assert_eq!(same, stacks);
+        crane("bottom").unwrap().apply(&mut same, &m).unwrap();
+        assert_eq!(same[1], labels(&["D", "M", "C"]));
+    }
+
+    #[test]
+    fn simulator_example1() {
+        let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
+        let (stacks, moves) = super::parse_input(format!(
+            "{}\n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\nmove 2 from 2 to 1\nmove 1 from 1 to 2",
+            drawing
+        ))
+        .unwrap();
+        assert_eq!(super::render_stacks(&stacks), drawing);
+
+        let crane = super::CrateMover9000;
+        let mut simulator = super::Simulator::new(&crane, stacks.clone(), &moves);
+        assert!(!simulator.back());
+
+        simulator.jump(2).unwrap();
+        assert_eq!(
+            simulator.render(),
+            "after 2 of 4: move 3 from 1 to 3
+        [Z]
+        [N]
+    [C] [D]
+    [M] [P]
+ 1   2   3 "
+        );
+        let after2 = simulator.stacks().clone();
+        assert_eq!(
+            super::parse_stacks(super::render_stacks(&after2)).unwrap(),
+            after2
+        );
+
+        while simulator.forward().unwrap() {}
+        assert_eq!(super::tops(simulator.stacks()), "CMZ");
+        assert!(simulator.back());
+        simulator.jump(2).unwrap();
+        assert_eq!(simulator.stacks(), &after2);
+        simulator.jump(0).unwrap();
+        assert_eq!(simulator.stacks(), &stacks);
+        assert!(simulator.jump(5).is_err());
+    }
+
+    #[test]
+    fn parse_stacks_wide_drawings() {
+        let ragged = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\nmove 1 from 2 to 1";
+        let (stacks, _) = super::parse_input(ragged).unwrap();
+        assert_eq!(super::tops(&stacks), "NDP");
+
+        let mut wide: super::Stacks = (1..=11).map(|i| vec![format!("{}", i * 7)]).collect();
+        wide[9].push("XL".to_string());
+        wide[2].clear();
+        let drawing = super::render_stacks(&wide);
+        assert_eq!(
+            drawing,
+            "                                           [XL]     
+[7] [14]     [28] [35] [42] [49] [56] [63] [70] [77]
+ 1   2    3   4    5    6    7    8    9    10   11 "
+        );
+        assert_eq!(super::parse_stacks(&drawing).unwrap(), wide);
+
+        let error = |drawing: &str| super::parse_stacks(drawing).unwrap_err().to_string();
+        assert!(error("    [A]\n[B]\n 1   2").contains("line 1 of the drawing: crate [A] floats"));
+        assert!(error("    [A]\n 1").contains("not above exactly one stack"));
+        assert!(error("[A] x\n 1").contains("\"x\" is not a crate"));
+        assert!(error("[A\n 1").contains("unclosed crate"));
+        assert!(error("[A]\n 1 3").contains("expected stack 2"));
+
+        let moves = "[A]\n 1   2\n\nmove 1 from 1 to 2\nmove 2 from 2 to 1\nmove 1 from 4 to 1";
+        assert_eq!(
+            super::parse(moves).unwrap_err().to_string(),
+            "parse error: move 2: move of 2 crates from stack 2 of 1; \
+             move 3: move from 4 to 1 with 2 stacks"
+        );
+        assert!(super::parse("[A]\n 1\n\nmove 1 from 0 to 1").is_err());
+
+        // The simulator runs the moves up to the first that cannot be made.
+        let (stacks, moves) = super::parse_input(moves).unwrap();
+        let crane = super::CrateMover9000;
+        let mut simulator = super::Simulator::new(&crane, stacks, &moves);
+        let error = simulator.jump(3).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "parse error: move 2: move of 2 crates from stack 2 of 1"
+        );
+        assert_eq!(simulator.step(), 1);
+    }
+}
@@ -0,0 +1,258 @@
+//! Day 6: Tuning Trouble.
+//!
+//! The input is a datastream of characters; a marker is a window of distinct
+//! characters. The stream is read one byte at a time, so it may be far larger
+//! than memory; line breaks are not part of it.
+
+use std::collections::VecDeque;
+use std::io::{BufReader, Bytes, Read};
+
+use crate::error::Error;
+
+/// The length of a start-of-packet marker; the `window_1` parameter.
+pub const WINDOW_1: usize = 4;
+/// The length of a start-of-message marker; the `window_2` parameter.
+pub const WINDOW_2: usize = 14;
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<u8> {
+    return input.as_ref().as_bytes().to_vec();
+}
+
+/// Whether the last `window` bytes are distinct, updated in constant time
+/// for each byte from the counts of the bytes in the window.
+#[derive(Debug, Clone)]
+pub struct Detector {
+    window: usize,
+    /// The last bytes, up to `window`, as a ring starting at `next`.
+    ring: Vec<u8>,
+    next: usize,
+    counts: [usize; 256],
+    /// The bytes found more than once in the window.
+    repeated: usize,
+}
+
+impl Detector {
+    pub fn new(window: usize) -> Result<Detector, Error> {
+        if window == 0 {
+            return Err(Error::ConfigError(
+                "a marker has at least one character".to_string(),
+            ));
+        }
+
+        return Ok(Detector {
+            window,
+            ring: Vec::with_capacity(window),
+            next: 0,
+            counts: [0; 256],
+            repeated: 0,
+        });
+    }
+
+    pub fn window(self: &Self) -> usize {
+        return self.window;
+    }
+
+    /// Takes the next byte; true when it ends a marker.
+    pub fn push(self: &mut Self, byte: u8) -> bool {
+        if self.ring.len() == self.window {
+            let old = std::mem::replace(&mut self.ring[self.next], byte);
+            self.next = (self.next + 1) % self.window;
+
+            self.counts[old as usize] -= 1;
+            if self.counts[old as usize] == 1 {
+                self.repeated -= 1;
+            }
+        } else {
+            self.ring.push(byte);
+        }
+
+        self.counts[byte as usize] += 1;
+        if self.counts[byte as usize] == 2 {
+            self.repeated += 1;
+        }
+
+        return self.ring.len() == self.window && self.repeated == 0;
+    }
+}
+
+/// A marker of `window` distinct bytes, ending after `end` bytes of the
+/// stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Marker {
+    pub window: usize,
+    pub end: usize,
+}
+
+/// Every marker of a stream, for some window lengths, in the order they end;
+/// markers of several lengths ending together follow the order of the
+/// lengths.
+pub struct Markers<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    detectors: Vec<Detector>,
+    /// The bytes of the stream read so far.
+    read: usize,
+    found: VecDeque<Marker>,
+}
+
+/// The markers of `reader` of the lengths of `windows`; a length given twice
+/// is looked for once.
+pub fn markers<R: Read>(reader: R, windows: &[usize]) -> Result<Markers<R>, Error> {
+    return Ok(Markers {
+        bytes: BufReader::new(reader).bytes(),
+        detectors: crate::collections::unique(windows.iter().copied())
+            .into_iter()
+            .map(Detector::new)
+            .collect::<Result<_, _>>()?,
+        read: 0,
+        found: VecDeque::new(),
+    });
+}
+
+impl<R: Read> Iterator for Markers<R> {
+    type Item = Result<Marker, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() {
+            let byte = match self.bytes.next()? {
+                Ok(b'\n' | b'\r') => continue,
+                Ok(byte) => byte,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.read += 1;
+
+            for detector in &mut self.detectors {
+                if detector.push(byte) {
+                    self.found.push_back(Marker {
+                        window: detector.window(),
+                        end: self.read,
+                    });
+                }
+            }
+        }
+
+        return self.found.pop_front().map(Ok);
+    }
+}
+
+/// The end of the first marker of each length of `windows`, or `None` when
+/// the stream has none; stops reading once they are all found.
+pub fn first_markers(reader: impl Read, windows: &[usize]) -> Result<Vec<Option<usize>>, Error> {
+    let mut firsts = vec![None; windows.len()];
+
+    for marker in markers(reader, windows)? {
+        let marker = marker?;
+        for (first, &window) in firsts.iter_mut().zip(windows) {
+            if window == marker.window && first.is_none() {
+                *first = Some(marker.end);
+            }
+        }
+        if firsts.iter().all(Option::is_some) {
+            break;
+        }
+    }
+
+    return Ok(firsts);
+}
+
+/// The number of characters read when the first `window` distinct characters
+/// end, or `None` when there is no such marker.
+pub fn solve(input: &[u8], window: usize) -> Result<Option<usize>, Error> {
+    return Ok(first_markers(input, &[window])?[0]);
+}
+
+/// The answer of a part: where the marker ends, or `not found`.
+fn answer(input: &[u8], window: usize) -> String {
+    return match solve(input, window).unwrap() {
+        Some(end) => end.to_string(),
+        None => "not found".to_string(),
+    };
+}
+
+/// The end of the first start-of-packet marker.
+pub fn part1(input: &Vec<u8>, window: usize) -> String {
+    return answer(input, window);
+}
+
+/// The end of the first start-of-message marker.
+pub fn part2(input: &Vec<u8>, window: usize) -> String {
+    return answer(input, window);
+}
+
+/// Day 6 for the runner, see [`crate::solution::Solution`].
+pub struct Day06;
+
+impl crate::solution::Solution for Day06 {
+    type Input<'a> = Vec<u8>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 6;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("window_1", WINDOW_1))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("window_2", WINDOW_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        assert_eq!(super::part1(&input, super::WINDOW_1), "7");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
+
+        assert_eq!(super::part2(&input, super::WINDOW_2), "19");
+    }
+
+    #[test]
+    fn markers_match_the_windows() {
+        for (stream, packet, message) in [
+            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+            ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw\n", 11, 26),
+        ] {
+            let firsts = super::first_markers(stream.as_bytes(), &[4, 14]).unwrap();
+            assert_eq!(firsts, vec![Some(packet), Some(message)]);
+        }
+
+        // The last window counts, and short streams have none.
+        assert_eq!(super::solve(b"aabcd", 4).unwrap(), Some(5));
+        assert_eq!(super::solve(b"abc", 4).unwrap(), None);
+        assert_eq!(super::solve(b"", 14).unwrap(), None);
+        assert!(super::solve(b"abc", 0).is_err());
+        assert_eq!(super::part2(&super::parse_input("abcabc"), 14), "not found");
+        assert_eq!(
+            super::first_markers(&b"abcd"[..], &[4, 2, 4]).unwrap(),
+            vec![Some(4), Some(2), Some(4)]
+        );
+        assert_eq!(super::markers(&b"abcd"[..], &[4, 4]).unwrap().count(), 1);
+
+        let stream = b"abcabbacdefaab";
+        let all: Vec<_> = super::markers(&stream[..], &[3, 1])
+            .unwrap()
+            .map(|marker| marker.unwrap())
+            .filter(|marker| marker.window == 3)
+            .map(|marker| marker.end)
+            .collect();
+        let expected: Vec<_> = (3..=stream.len())
+            .filter(|&end| {
+                let window = &stream[end - 3..end];
+                window[0] != window[1] && window[1] != window[2] && window[0] != window[2]
+            })
+            .collect();
+        assert_eq!(all, expected);
+    }
+}
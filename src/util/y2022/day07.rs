@@ -0,0 +1,207 @@
+//! Day 7: No Space Left On Device.
+//!
+//! The input is a terminal session that walks the file system depth first with
+//! `cd` and `ls`.
+
+use std::str::FromStr;
+
+/// The size of the disk; the `total_space` parameter.
+pub const TOTAL_SPACE: usize = 70000000;
+/// The free space the update needs; the `update_space` parameter.
+pub const UPDATE_SPACE: usize = 30000000;
+
+/// One line of an `ls` listing.
+#[derive(Debug, Clone)]
+pub enum Node {
+    /// `dir <name>`
+    Directory(String),
+    /// `<size> <name>`
+    File(String, usize),
+}
+
+impl FromStr for Node {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (lhs, rhs) = s
+            .split_once(' ')
+            .ok_or(Self::Err::ParseError("failed to split line".to_string()))?;
+
+        return Ok(match lhs {
+            "dir" => Node::Directory(rhs.to_string()),
+            lhs => Node::File(
+                rhs.to_string(),
+                lhs.parse()
+                    .map_err(|_| Self::Err::ParseError(format!("failed to parse size {}", lhs)))?,
+            ),
+        });
+    }
+}
+
+/// Returns the listing of every directory, in the order they were visited.
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Vec<Node>> {
+    input
+        .as_ref()
+        .split("$ ls\n")
+        .map(|r| {
+            r.lines()
+                .filter_map(|line| line.parse::<Node>().ok())
+                .collect::<Vec<Node>>()
+        })
+        .skip(1)
+        .collect::<Vec<Vec<Node>>>()
+}
+
+/// Returns the size of `node`, consuming its listings from `walk` and pushing
+/// the size of every directory below it (itself included) to `result`.
+pub fn dfs(node: Node, walk: &mut Vec<Vec<Node>>, result: &mut Vec<usize>) -> usize {
+    match node {
+        Node::File(_, size) => size,
+        Node::Directory(_) => {
+            let nodes = walk.remove(0);
+
+            let size = nodes
+                .into_iter()
+                .map(|node| dfs(node, walk, result))
+                .sum::<usize>();
+            result.push(size);
+
+            return size;
+        }
+    }
+}
+
+/// The total size of the directories of at most 100000.
+pub fn part1(input: &Vec<Vec<Node>>) -> String {
+    let root = Node::Directory("/".to_string());
+    let mut walk = input.to_vec();
+    let mut result = Vec::new();
+
+    _ = dfs(root, &mut walk, &mut result);
+
+    result
+        .into_iter()
+        .filter(|&size| size <= 100000)
+        .sum::<usize>()
+        .to_string()
+}
+
+/// The size of the smallest directory that frees up enough space for the update.
+pub fn part2(input: &Vec<Vec<Node>>, total_space: usize, update_space: usize) -> String {
+    let root = Node::Directory("/".to_string());
+    let mut walk = input.to_vec();
+    let mut result = Vec::new();
+
+    let size = dfs(root, &mut walk, &mut result);
+
+    let available_space = total_space - size;
+    if available_space >= update_space {
+        panic!("enough space for update");
+    }
+
+    let required_space = update_space - available_space;
+
+    result.sort();
+
+    for size in result {
+        if size >= required_space {
+            return size.to_string();
+        }
+    }
+
+    unreachable!();
+}
+
+/// Day 7 for the runner, see [`crate::solution::Solution`].
+pub struct Day07;
+
+impl crate::solution::Solution for Day07 {
+    type Input<'a> = Vec<Vec<Node>>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 7;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(
+            input,
+            params.get("total_space", TOTAL_SPACE),
+            params.get("update_space", UPDATE_SPACE),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+        );
+
+        assert_eq!(super::part1(&input), "95437");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k",
+        );
+
+        assert_eq!(
+            super::part2(&input, super::TOTAL_SPACE, super::UPDATE_SPACE),
+            "24933642"
+        );
+    }
+}
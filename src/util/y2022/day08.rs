@@ -0,0 +1,156 @@
+//! Day 8: Treetop Tree House.
+//!
+//! The input is a grid of tree heights from 0 to 9.
+
+use take_until::TakeUntilExt;
+
+/// Returns the grid of heights, one row per line.
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Vec<u8>> {
+    input
+        .as_ref()
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).expect("to only have numbers in the input") as u8)
+                .collect::<Vec<u8>>()
+        })
+        .collect::<Vec<Vec<u8>>>()
+}
+
+/// Whether the tree at `x`, `y` can be seen from outside the grid.
+pub fn is_visible(map: &Vec<Vec<u8>>, x: usize, y: usize) -> bool {
+    let line_len = map[y].len();
+    if x == 0 || x == line_len - 1 {
+        return true;
+    }
+
+    let len = map.len();
+    if y == 0 || y == len - 1 {
+        return true;
+    }
+
+    let height = map[x][y];
+
+    return (0..y).all(|j| height > map[x][j])
+        || (y + 1..len).all(|j| height > map[x][j])
+        || (0..x).all(|i| height > map[i][y])
+        || (x + 1..line_len).all(|i| height > map[i][y]);
+}
+
+/// The product of the viewing distances of the tree at `x`, `y`.
+pub fn scenic_score(map: &Vec<Vec<u8>>, x: usize, y: usize) -> usize {
+    let line_len = map[y].len();
+    if x == 0 || x == line_len - 1 {
+        return 0;
+    }
+
+    let len = map.len();
+    if y == 0 || y == len - 1 {
+        return 0;
+    }
+
+    let height = map[x][y];
+
+    return map[x][0..y]
+        .iter()
+        .rev()
+        .take_until(|&e| e >= &height)
+        .count()
+        * map[x][y + 1..len]
+            .iter()
+            .take_until(|&e| e >= &height)
+            .count()
+        * map[0..x]
+            .iter()
+            .map(|line| line[y])
+            .rev()
+            .take_until(|&e| e >= height)
+            .count()
+        * map[x + 1..line_len]
+            .iter()
+            .map(|line| line[y])
+            .take_until(|&e| e >= height)
+            .count();
+}
+
+/// The number of trees visible from outside the grid.
+pub fn part1(input: &Vec<Vec<u8>>) -> String {
+    let mut count = 0;
+
+    for y in 0..input.len() {
+        for x in 0..input[y].len() {
+            if is_visible(input, x, y) {
+                count += 1;
+            }
+        }
+    }
+
+    return count.to_string();
+}
+
+/// The highest scenic score of any tree.
+pub fn part2(input: &Vec<Vec<u8>>) -> String {
+    let mut best = 0;
+
+    for y in 0..input.len() {
+        for x in 0..input[y].len() {
+            let score = scenic_score(input, x, y);
+            if score > best {
+                best = score;
+            }
+        }
+    }
+
+    return best.to_string();
+}
+
+/// Day 8 for the runner, see [`crate::solution::Solution`].
+pub struct Day08;
+
+impl crate::solution::Solution for Day08 {
+    type Input<'a> = Vec<Vec<u8>>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 8;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "30373
+25512
+65332
+33549
+35390",
+        );
+
+        assert_eq!(super::part1(&input), "21");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "30373
+25512
+65332
+33549
+35390",
+        );
+
+        assert_eq!(super::part2(&input), "8");
+    }
+}
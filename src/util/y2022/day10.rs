@@ -0,0 +1,456 @@
+//! Day 10: Cathode-Ray Tube.
+//!
+//! The input is a program for the CPU of the handheld device, which drives the
+//! sprite drawn on its 40x6 CRT.
+
+use std::str::FromStr;
+
+/// An instruction of the CPU.
+#[derive(Debug)]
+pub enum Instr {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Instr {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("noop") {
+            return Ok(Instr::Noop);
+        } else if s.starts_with("addx") {
+            let (_, value) = s.split_once(' ').ok_or_else(|| {
+                Self::Err::ParseError("expected addx to have on argument: {s}".to_string())
+            })?;
+            let value = value
+                .parse::<i32>()
+                .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+            return Ok(Instr::Addx(value));
+        } else {
+            return Err(Self::Err::ParseError(
+                "unexpected instruction {s}".to_string(),
+            ));
+        }
+    }
+}
+
+impl Instr {
+    /// The number of cycles the instruction takes to complete.
+    pub fn cycles(self: &Self) -> usize {
+        match self {
+            Instr::Noop => 1,
+            Instr::Addx(_) => 2,
+        }
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Instr> {
+    crate::parsing::lines_to_vec::<Instr>(input).expect("aoc to give correct input")
+}
+
+/// The sum of the signal strengths during the 20th, 60th, ..., 220th cycles.
+pub fn part1(input: &Vec<Instr>) -> String {
+    let mut total_cycles = 0;
+    let mut sum = 0;
+    let mut x = 1;
+
+    for instr in input {
+        let cycles = instr.cycles();
+
+        for _ in 0..cycles {
+            total_cycles += 1;
+
+            if (total_cycles == 20) || (total_cycles > 20 && (total_cycles - 20) % 40 == 0) {
+                sum += x * total_cycles;
+            }
+        }
+
+        match instr {
+            Instr::Noop => (),
+            Instr::Addx(v) => x += v,
+        }
+    }
+
+    return sum.to_string();
+}
+
+/// The image drawn on the CRT, one line per row.
+pub fn part2(input: &Vec<Instr>) -> String {
+    let mut total_cycles: usize = 0;
+    let mut x: i32 = 1;
+
+    let mut crt = vec![vec!['.'; 40]; 6];
+
+    for instr in input {
+        let cycles = instr.cycles();
+
+        for _ in 0..cycles {
+            let crt_row: usize = total_cycles / 40;
+            let crt_col: usize = total_cycles % 40;
+
+            if (x.unsigned_abs() as usize).abs_diff(crt_col) <= 1 {
+                crt[crt_row][crt_col] = '#';
+            }
+
+            total_cycles += 1;
+        }
+
+        match instr {
+            Instr::Noop => (),
+            Instr::Addx(v) => x += v,
+        }
+    }
+
+    return crt
+        .into_iter()
+        .map(|row| row.into_iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+/// Day 10 for the runner, see [`crate::solution::Solution`].
+pub struct Day10;
+
+impl crate::solution::Solution for Day10 {
+    type Input<'a> = Vec<Instr>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 10;
+    const HAS_RENDER: bool = true;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+
+    /// The CRT, which is also the answer of part 2.
+    fn render(input: &Self::Input<'_>, _: &crate::config::Params) -> Option<String> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop",
+        );
+
+        assert_eq!(super::part1(&input), "13140");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop",
+        );
+
+        assert_eq!(
+            super::part2(&input),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+}
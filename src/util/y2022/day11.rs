@@ -0,0 +1,397 @@
+//! Day 11: Monkey in the Middle.
+//!
+//! The input describes the monkeys: the items they hold, how they change the
+//! worry level and the test that picks the monkey an item is thrown to.
+
+use std::{collections::VecDeque, str::FromStr};
+
+/// How a monkey changes the worry level of an item: `new = old * 19`,
+/// `new = old + 6` or `new = old * old`.
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Multi(usize),
+    Add(usize),
+    Square,
+}
+
+impl FromStr for Operation {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (_, op) = s.split_once('=').ok_or(Self::Err::ParseError(
+            "operation should have an = sign".to_string(),
+        ))?;
+        let op = op.trim();
+
+        if op.contains('+') {
+            let (_, rhs) = op.split_once('+').ok_or(Self::Err::ParseError(
+                "operation should have a + sign".to_string(),
+            ))?;
+
+            let rhs = rhs
+                .trim()
+                .parse::<usize>()
+                .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+            return Ok(Operation::Add(rhs));
+        } else if op.contains('*') {
+            let (_, rhs) = op.split_once('*').ok_or(Self::Err::ParseError(
+                "operation should have a * sign".to_string(),
+            ))?;
+
+            if let Ok(rhs) = rhs.trim().parse::<usize>() {
+                return Ok(Operation::Multi(rhs));
+            } else {
+                return Ok(Operation::Square);
+            }
+        } else {
+            return Err(Self::Err::ParseError("invalid operation".to_string()));
+        }
+    }
+}
+
+impl Operation {
+    pub fn apply(self: &Self, value: usize) -> usize {
+        match self {
+            Operation::Multi(v) => v * value,
+            Operation::Add(v) => v + value,
+            Operation::Square => value * value,
+        }
+    }
+}
+
+/// Throws an item to `true_index` when its worry level is divisible by
+/// `divisor`, and to `false_index` otherwise.
+#[derive(Debug, Clone)]
+pub struct Test {
+    pub divisor: usize,
+    pub true_index: usize,
+    pub false_index: usize,
+}
+
+impl FromStr for Test {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let divisor = lines
+            .next()
+            .ok_or(Self::Err::ParseError(
+                "should have a line for test".to_string(),
+            ))?
+            .split(' ')
+            .next_back()
+            .ok_or(Self::Err::ParseError(
+                "condition should be correct".to_string(),
+            ))?
+            .parse::<usize>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        let true_index = lines
+            .next()
+            .ok_or(Self::Err::ParseError(
+                "should have a line for true index".to_string(),
+            ))?
+            .split(' ')
+            .next_back()
+            .ok_or(Self::Err::ParseError(
+                "should have items for true index".to_string(),
+            ))?
+            .parse::<usize>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+        let false_index = lines
+            .next()
+            .ok_or(Self::Err::ParseError(
+                "should have a line for false index".to_string(),
+            ))?
+            .split(' ')
+            .next_back()
+            .ok_or(Self::Err::ParseError(
+                "should have items for false index".to_string(),
+            ))?
+            .parse::<usize>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        return Ok(Test {
+            divisor,
+            true_index,
+            false_index,
+        });
+    }
+}
+
+impl Test {
+    /// The monkey an item with the worry level `value` is thrown to.
+    pub fn test(self: &Self, value: usize) -> usize {
+        if value.is_multiple_of(self.divisor) {
+            return self.true_index;
+        } else {
+            return self.false_index;
+        }
+    }
+}
+
+/// A monkey, ordered by the number of items it inspected.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: VecDeque<usize>,
+    pub operation: Operation,
+    pub test: Test,
+
+    pub inspections: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines = s.lines().collect::<Vec<&str>>();
+
+        let (_, items) = lines[1]
+            .split_once(':')
+            .ok_or(Self::Err::ParseError("should have : for items".to_string()))?;
+        let items = items
+            .trim()
+            .split(", ")
+            .map(|item| {
+                item.parse::<usize>()
+                    .map_err(|e| Self::Err::ParseError(e.to_string()))
+            })
+            .collect::<Result<VecDeque<usize>, Self::Err>>()?;
+
+        let operation = lines[2].parse::<Operation>()?;
+
+        let test = lines[3..].join("\n").parse::<Test>()?;
+
+        return Ok(Monkey {
+            items,
+            operation,
+            test,
+
+            inspections: 0,
+        });
+    }
+}
+
+impl PartialEq for Monkey {
+    fn eq(&self, other: &Self) -> bool {
+        self.inspections == other.inspections
+    }
+}
+
+impl Eq for Monkey {}
+
+impl PartialOrd for Monkey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Monkey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.inspections.cmp(&other.inspections)
+    }
+}
+
+impl Monkey {
+    fn act1(self: &mut Self) -> Option<(usize, usize)> {
+        if let Some(item) = self.items.pop_front() {
+            let item = self.operation.apply(item) / 3;
+            let next = self.test.test(item);
+
+            self.inspections += 1;
+
+            return Some((item, next));
+        }
+
+        return None;
+    }
+
+    fn act2(self: &mut Self, divisor: usize) -> Option<(usize, usize)> {
+        if let Some(item) = self.items.pop_front() {
+            let item = self.operation.apply(item) % divisor;
+            let next = self.test.test(item);
+
+            self.inspections += 1;
+
+            return Some((item, next));
+        }
+
+        return None;
+    }
+
+    /// Inspects and throws every item, dividing the worry levels by 3; returns
+    /// the `(item, monkey)` throws.
+    pub fn round1(self: &mut Self) -> Vec<(usize, usize)> {
+        let mut actions = Vec::new();
+
+        while let Some(act) = self.act1() {
+            actions.push(act);
+        }
+
+        return actions;
+    }
+
+    /// Inspects and throws every item, keeping the worry levels modulo
+    /// `divisor`; returns the `(item, monkey)` throws.
+    pub fn round2(self: &mut Self, divisor: usize) -> Vec<(usize, usize)> {
+        let mut actions = Vec::new();
+
+        while let Some(act) = self.act2(divisor) {
+            actions.push(act);
+        }
+
+        return actions;
+    }
+
+    /// Catches an item thrown by another monkey.
+    pub fn give(self: &mut Self, item: usize) {
+        self.items.push_back(item);
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Monkey> {
+    input
+        .as_ref()
+        .split("\n\n")
+        .map(|m| m.parse::<Monkey>())
+        .collect::<Result<Vec<Monkey>, crate::error::Error>>()
+        .expect("to have correct aoc input")
+}
+
+/// The monkey business after 20 rounds with relief.
+pub fn part1(input: &Vec<Monkey>) -> String {
+    let mut monkeys = input.to_vec();
+
+    for _ in 0..20 {
+        for i in 0..monkeys.len() {
+            let actions = monkeys[i].round1();
+
+            for (item, next) in actions {
+                monkeys[next].give(item);
+            }
+        }
+    }
+
+    monkeys.sort_by(|a, b| b.cmp(a));
+
+    return (monkeys[0].inspections * monkeys[1].inspections).to_string();
+}
+
+/// The monkey business after 10000 rounds without relief.
+pub fn part2(input: &Vec<Monkey>) -> String {
+    let mut monkeys = input.to_vec();
+
+    let common_div = crate::math::lcm_all(input.iter().map(|m| m.test.divisor))
+        .expect("the divisors to have a small lcm");
+
+    for _ in 0..10000 {
+        for i in 0..monkeys.len() {
+            let actions = monkeys[i].round2(common_div);
+
+            for (item, next) in actions {
+                monkeys[next].give(item);
+            }
+        }
+    }
+
+    monkeys.sort_by(|a, b| b.cmp(a));
+
+    return (monkeys[0].inspections * monkeys[1].inspections).to_string();
+}
+
+/// Day 11 for the runner, see [`crate::solution::Solution`].
+pub struct Day11;
+
+impl crate::solution::Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 11;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+        );
+
+        assert_eq!(super::part1(&input), "10605");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1",
+        );
+
+        assert_eq!(super::part2(&input), "2713310158");
+    }
+}
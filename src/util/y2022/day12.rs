@@ -0,0 +1,215 @@
+//! Day 12: Hill Climbing Algorithm.
+//!
+//! The input is a heightmap from `a` to `z`, with the start marked `S` and the
+//! best signal marked `E`.
+
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap},
+    str::FromStr,
+};
+
+/// The heightmap, indexed by `(row, column)` with heights from 0 to 25.
+#[derive(Debug)]
+pub struct Map {
+    pub map: HashMap<(usize, usize), usize>,
+    pub width: usize,
+    pub height: usize,
+    pub src: (usize, usize),
+    pub dest: (usize, usize),
+}
+
+impl FromStr for Map {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect::<Vec<Vec<char>>>();
+
+        let mut map = HashMap::new();
+        let mut src = (0, 0);
+        let mut dest = (0, 0);
+
+        let height = lines.len();
+        let width = lines[0].len();
+
+        for (x, line) in lines.iter_mut().enumerate() {
+            for (y, c) in line.iter_mut().enumerate() {
+                if *c == 'S' {
+                    src = (x, y);
+                    *c = 'a';
+                } else if *c == 'E' {
+                    dest = (x, y);
+                    *c = 'z';
+                }
+
+                map.insert((x, y), *c as usize - 'a' as usize);
+            }
+        }
+
+        return Ok(Map {
+            map,
+            width,
+            height,
+            src,
+            dest,
+        });
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Map {
+    input
+        .as_ref()
+        .parse::<Map>()
+        .expect("to have correct input")
+}
+
+fn neighbors(map: &Map, node: (usize, usize)) -> Vec<(usize, usize)> {
+    let edges = &map.map;
+    let value = edges.get(&node).unwrap();
+
+    return crate::grid::neighbors4(node, map.height, map.width)
+        .filter(|next| value + 1 >= *edges.get(next).unwrap())
+        .collect();
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct State {
+    cost: usize,
+    position: (usize, usize),
+}
+
+impl Ord for State {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .cmp(&self.cost)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for State {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The fewest steps from `map.src` to `map.dest`, climbing at most one unit
+/// at a time.
+pub fn shortest_path(map: &Map) -> Option<usize> {
+    let start = map.src;
+    let goal = map.dest;
+    let edges = &map.map;
+
+    let mut dist: HashMap<_, _> = edges.keys().map(|&k| (k, usize::MAX)).collect();
+
+    let mut heap = BinaryHeap::new();
+
+    dist.insert(start, 0);
+    heap.push(State {
+        cost: 0,
+        position: start,
+    });
+
+    while let Some(State { cost, position }) = heap.pop() {
+        if position == goal {
+            return Some(cost);
+        }
+
+        if cost > *dist.get(&position).unwrap() {
+            continue;
+        }
+
+        for edge in neighbors(map, position) {
+            if cost + 1 < *dist.get(&edge).unwrap() {
+                heap.push(State {
+                    cost: cost + 1,
+                    position: edge,
+                });
+                dist.insert(edge, cost + 1);
+            }
+        }
+    }
+
+    return None;
+}
+
+/// The fewest steps from the start to the best signal.
+pub fn part1(input: &Map) -> String {
+    return shortest_path(input).unwrap().to_string();
+}
+
+/// The fewest steps from any square at elevation `a` to the best signal.
+pub fn part2(input: &Map) -> String {
+    return input
+        .map
+        .iter()
+        .filter(|(_, &v)| return v == 0)
+        .filter_map(|(&k, _)| {
+            let test_input = Map {
+                map: input.map.clone(),
+                width: input.width,
+                height: input.height,
+                src: k,
+                dest: input.dest,
+            };
+
+            return shortest_path(&test_input);
+        })
+        .min()
+        .unwrap()
+        .to_string();
+}
+
+/// Day 12 for the runner, see [`crate::solution::Solution`].
+pub struct Day12;
+
+impl crate::solution::Solution for Day12 {
+    type Input<'a> = Map;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 12;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+        );
+
+        assert_eq!(super::part1(&input), "31");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi",
+        );
+
+        assert_eq!(super::part2(&input), "29");
+    }
+}
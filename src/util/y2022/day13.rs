@@ -0,0 +1,284 @@
+//! Day 13: Distress Signal.
+//!
+//! The input holds pairs of packets separated by blank lines; a packet is a
+//! nested list of integers such as `[1,[2,[3]]]`.
+
+use std::{iter::Peekable, str::FromStr};
+
+/// A packet or one of its elements, ordered by the rules of the puzzle.
+#[derive(Debug, Clone)]
+pub enum PacketData {
+    List(Vec<PacketData>),
+    Elem(usize),
+}
+
+impl PartialEq for PacketData {
+    fn eq(&self, other: &Self) -> bool {
+        match self {
+            PacketData::Elem(value) => match other {
+                PacketData::Elem(other_value) => value.eq(other_value),
+                PacketData::List(other_vec) => PacketData::List(vec![PacketData::Elem(*value)])
+                    .eq(&PacketData::List(other_vec.to_vec())),
+            },
+            PacketData::List(vec) => match other {
+                PacketData::Elem(other_value) => PacketData::List(vec.to_vec())
+                    .eq(&PacketData::List(vec![PacketData::Elem(*other_value)])),
+                PacketData::List(other_vec) => {
+                    for (x, y) in vec.iter().zip(other_vec) {
+                        if !x.eq(y) {
+                            return false;
+                        }
+                    }
+
+                    return vec.len().eq(&other_vec.len());
+                }
+            },
+        }
+    }
+}
+
+impl Eq for PacketData {}
+
+impl PartialOrd for PacketData {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for PacketData {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self {
+            PacketData::Elem(value) => match other {
+                PacketData::Elem(other_value) => value.cmp(other_value),
+                PacketData::List(other_vec) => PacketData::List(vec![PacketData::Elem(*value)])
+                    .cmp(&PacketData::List(other_vec.to_vec())),
+            },
+            PacketData::List(vec) => match other {
+                PacketData::Elem(other_value) => PacketData::List(vec.to_vec())
+                    .cmp(&PacketData::List(vec![PacketData::Elem(*other_value)])),
+                PacketData::List(other_vec) => {
+                    for (x, y) in vec.iter().zip(other_vec) {
+                        if !x.eq(y) {
+                            return x.cmp(y);
+                        }
+                    }
+
+                    return vec.len().cmp(&other_vec.len());
+                }
+            },
+        }
+    }
+}
+
+impl FromStr for PacketData {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return from_str_helper(&mut s.chars().peekable());
+    }
+}
+
+fn from_str_helper<I>(chars: &mut Peekable<I>) -> Result<PacketData, crate::error::Error>
+where
+    I: Iterator<Item = char>,
+{
+    match chars.next() {
+        Some('[') => {
+            if let Some(']') = chars.peek() {
+                chars.next();
+                return Ok(PacketData::List(vec![]));
+            }
+
+            let mut data = Vec::new();
+
+            loop {
+                data.push(from_str_helper(chars)?);
+
+                if let Some(']') = chars.next() {
+                    break;
+                }
+            }
+
+            return Ok(PacketData::List(data));
+        }
+        Some(c) => {
+            let mut digits = vec![c];
+
+            loop {
+                if chars.peek() == Some(&']') || chars.peek() == Some(&',') {
+                    break;
+                }
+
+                if let Some(c) = chars.next() {
+                    digits.push(c);
+                }
+            }
+
+            let value = digits
+                .iter()
+                .collect::<String>()
+                .parse::<usize>()
+                .map_err(|e| crate::error::Error::ParseError(e.to_string()))?;
+
+            return Ok(PacketData::Elem(value));
+        }
+        None => unreachable!(),
+    }
+}
+
+/// A pair of packets.
+#[derive(Debug)]
+pub struct Packet {
+    pub lhs: PacketData,
+    pub rhs: PacketData,
+}
+
+impl FromStr for Packet {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+
+        let lhs = lines
+            .next()
+            .ok_or(Self::Err::ParseError("expect first line".to_string()))?
+            .parse::<PacketData>()?;
+        let rhs = lines
+            .next()
+            .ok_or(Self::Err::ParseError("expect second line".to_string()))?
+            .parse::<PacketData>()?;
+
+        return Ok(Packet { lhs, rhs });
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Packet> {
+    input
+        .as_ref()
+        .split("\n\n")
+        .map(|p| p.parse::<Packet>())
+        .collect::<Result<Vec<Packet>, _>>()
+        .expect("aoc to have correct input")
+}
+
+/// The sum of the 1-based indices of the pairs that are in the right order.
+pub fn part1(input: &Vec<Packet>) -> String {
+    (1..input.len() + 1)
+        .filter(|&i| input[i - 1].lhs < input[i - 1].rhs)
+        .sum::<usize>()
+        .to_string()
+}
+
+/// The decoder key: the product of the positions of the divider packets
+/// `[[2]]` and `[[6]]` once every packet is sorted.
+pub fn part2(input: &Vec<Packet>) -> String {
+    let mut packets = input
+        .iter()
+        .flat_map(|p| vec![p.lhs.clone(), p.rhs.clone()])
+        .collect::<Vec<PacketData>>();
+
+    packets.push(PacketData::List(vec![PacketData::Elem(2)]));
+    packets.push(PacketData::List(vec![PacketData::Elem(6)]));
+
+    packets.sort();
+
+    let mut result = 1;
+
+    for (i, packet) in packets.iter().enumerate() {
+        if *packet == PacketData::List(vec![PacketData::Elem(2)]) {
+            result *= i + 1;
+        }
+        if *packet == PacketData::List(vec![PacketData::Elem(6)]) {
+            result *= i + 1;
+        }
+    }
+
+    return result.to_string();
+}
+
+/// Day 13 for the runner, see [`crate::solution::Solution`].
+pub struct Day13;
+
+impl crate::solution::Solution for Day13 {
+    type Input<'a> = Vec<Packet>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 13;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+        );
+
+        assert_eq!(super::part1(&input), "13");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]",
+        );
+
+        assert_eq!(super::part2(&input), "140");
+    }
+}
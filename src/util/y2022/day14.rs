@@ -0,0 +1,333 @@
+//! Day 14: Regolith Reservoir.
+//!
+//! Every line of the input is a path of rock such as `498,4 -> 498,6 -> 496,6`;
+//! sand pours in from `500,0`.
+
+use std::{collections::HashSet, str::FromStr};
+
+/// A position in the cave, with `y` growing downwards.
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl FromStr for Point {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(",").ok_or(Self::Err::ParseError(
+            "expect to have 2 numbers sep by ','".to_string(),
+        ))?;
+
+        let x = x
+            .parse::<usize>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+        let y = y
+            .parse::<usize>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        return Ok(Point::new(x, y));
+    }
+}
+
+impl Point {
+    fn new(x: usize, y: usize) -> Self {
+        return Point { x, y };
+    }
+
+    fn span(self: &Self, other: &Self) -> Vec<Self> {
+        let min_x = self.x.min(other.x);
+        let max_x = self.x.max(other.x);
+        let min_y = self.y.min(other.y);
+        let max_y = self.y.max(other.y);
+
+        let mut span = Vec::new();
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                span.push(Point::new(x, y));
+            }
+        }
+
+        return span;
+    }
+}
+
+/// A path of rock through the given corners.
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub points: Vec<Point>,
+}
+
+impl FromStr for Line {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points = s
+            .split(" -> ")
+            .map(|p| p.parse::<Point>())
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Line { points });
+    }
+}
+
+impl From<Line> for Vec<Point> {
+    fn from(val: Line) -> Self {
+        let mut points = Vec::new();
+
+        for i in 0..val.points.len() - 1 {
+            let mut span = val.points[i].span(&val.points[i + 1]);
+            points.append(&mut span);
+        }
+
+        return points;
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Line> {
+    crate::parsing::lines_to_vec::<Line>(input.as_ref()).expect("to have correct aoc input")
+}
+
+/// The cave: its rocks and the sand that came to rest so far.
+///
+/// `Display` draws it the way the puzzle does, with `#` for rock, `o` for sand
+/// and `+` for the source.
+#[derive(Debug, Clone)]
+pub struct World {
+    pub rocks: HashSet<Point>,
+    pub sands: HashSet<Point>,
+}
+
+impl World {
+    pub fn new(rocks: HashSet<Point>) -> Self {
+        Self {
+            rocks,
+            sands: HashSet::new(),
+        }
+    }
+
+    /// Drops one unit of sand into the bottomless cave; returns where it came
+    /// to rest, or `None` once it falls into the abyss.
+    pub fn spawn_part1(self: &mut Self) -> Option<Point> {
+        let max_y = self.rocks.clone().into_iter().map(|p| p.y).max()?;
+
+        let mut p = Point::new(500, 0);
+
+        loop {
+            if p.y + 1 > max_y {
+                return None;
+            }
+
+            let pn = Point::new(p.x, p.y + 1);
+            if !self.rocks.contains(&pn) && !self.sands.contains(&pn) {
+                p.y += 1;
+                continue;
+            }
+
+            let pn = Point::new(p.x - 1, p.y + 1);
+            if !self.rocks.contains(&pn) && !self.sands.contains(&pn) {
+                p.x -= 1;
+                p.y += 1;
+                continue;
+            }
+
+            let pn = Point::new(p.x + 1, p.y + 1);
+            if !self.rocks.contains(&pn) && !self.sands.contains(&pn) {
+                p.x += 1;
+                p.y += 1;
+                continue;
+            }
+
+            self.sands.insert(p);
+            return Some(p);
+        }
+    }
+
+    /// Drops one unit of sand onto the floor two units below the lowest rock;
+    /// returns where it came to rest.
+    pub fn spawn_part2(self: &mut Self) -> Option<Point> {
+        let max_y = self.rocks.clone().into_iter().map(|p| p.y).max()?;
+        let floor_y = max_y + 2;
+
+        let mut p = Point::new(500, 0);
+
+        loop {
+            if p.y + 1 >= floor_y {
+                self.sands.insert(p);
+                return Some(p);
+            }
+
+            let pn = Point::new(p.x, p.y + 1);
+            if !self.rocks.contains(&pn) && !self.sands.contains(&pn) {
+                p.y += 1;
+                continue;
+            }
+
+            let pn = Point::new(p.x - 1, p.y + 1);
+            if !self.rocks.contains(&pn) && !self.sands.contains(&pn) {
+                p.x -= 1;
+                p.y += 1;
+                continue;
+            }
+
+            let pn = Point::new(p.x + 1, p.y + 1);
+            if !self.rocks.contains(&pn) && !self.sands.contains(&pn) {
+                p.x += 1;
+                p.y += 1;
+                continue;
+            }
+
+            self.sands.insert(p);
+            return Some(p);
+        }
+    }
+}
+
+impl From<Vec<Line>> for World {
+    fn from(value: Vec<Line>) -> Self {
+        let rocks = value.into_iter().fold(HashSet::new(), |mut acc, line| {
+            let points: Vec<Point> = line.into();
+            acc.extend(points);
+            return acc;
+        });
+
+        return World::new(rocks);
+    }
+}
+
+impl std::fmt::Display for World {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (min_x, max_x, _, max_y) = self.rocks.clone().into_iter().fold(
+            (usize::MAX, usize::MIN, usize::MAX, usize::MIN),
+            |(min_x, max_x, min_y, max_y), p| {
+                (
+                    min_x.min(p.x),
+                    max_x.max(p.x),
+                    min_y.min(p.y),
+                    max_y.max(p.y),
+                )
+            },
+        );
+
+        let min_y = 0;
+
+        let width = max_x - min_x + 1;
+        let height = max_y - min_y + 1;
+
+        let map =
+            self.rocks
+                .clone()
+                .into_iter()
+                .fold(vec![vec!['.'; width]; height], |mut acc, p| {
+                    acc[p.y - min_y][p.x - min_x] = '#';
+                    return acc;
+                });
+
+        let mut map = self.sands.clone().into_iter().fold(map, |mut acc, p| {
+            if p.y > min_y && p.x > min_x && p.y - min_y < height && p.x - min_x < width {
+                acc[p.y - min_y][p.x - min_x] = 'o';
+            }
+            return acc;
+        });
+
+        map[0][500 - min_x] = '+';
+
+        let map = map
+            .into_iter()
+            .map(|line| line.into_iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        return write!(f, "{}", map);
+    }
+}
+
+/// The units of sand that come to rest before sand starts falling into the abyss.
+pub fn part1(input: &Vec<Line>) -> String {
+    let mut world: World = input.to_vec().into();
+    while world.spawn_part1().is_some() {}
+    crate::debug!("the cave once the sand falls into the abyss:\n{}", world);
+    return world.sands.len().to_string();
+}
+
+/// The units of sand that come to rest before the source is blocked.
+pub fn part2(input: &Vec<Line>) -> String {
+    let mut world: World = input.to_vec().into();
+    while let Some(p) = world.spawn_part2() {
+        if p.x == 500 && p.y == 0 {
+            break;
+        }
+    }
+    crate::debug!("the cave once the source is blocked:\n{}", world);
+    return world.sands.len().to_string();
+}
+
+/// The cave once the sand of part 1 came to rest.
+pub fn render(input: &Vec<Line>) -> String {
+    let mut world: World = input.to_vec().into();
+    while world.spawn_part1().is_some() {}
+    return world.to_string();
+}
+
+/// Day 14 for the runner, see [`crate::solution::Solution`].
+pub struct Day14;
+
+impl crate::solution::Solution for Day14 {
+    type Input<'a> = Vec<Line>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 14;
+    const HAS_RENDER: bool = true;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+
+    fn render(input: &Self::Input<'_>, _: &crate::config::Params) -> Option<String> {
+        Some(render(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+        );
+
+        assert_eq!(super::part1(&input), "24");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+        );
+
+        assert_eq!(super::part2(&input), "93");
+    }
+
+    #[test]
+    fn render_example1() {
+        let input = super::parse_input(
+            "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9",
+        );
+
+        let render = super::render(&input);
+        assert_eq!(render.lines().next(), Some("......+..."));
+        assert_eq!(render.matches('o').count(), 24);
+    }
+}
@@ -0,0 +1,271 @@
+//! Day 15: Beacon Exclusion Zone.
+//!
+//! Every line of the input holds the position of a sensor and of the closest
+//! beacon it detects.
+
+use itertools::Itertools;
+use std::str::FromStr;
+
+/// The row checked by part 1; the `y_level_1` parameter.
+pub const Y_LEVEL_1: i64 = 2000000;
+/// The largest coordinate searched by part 2; the `y_level_2` parameter.
+pub const Y_LEVEL_2: i64 = 4000000;
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        return Point { x, y };
+    }
+
+    pub fn manhattan(self: &Self, other: &Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+#[derive(Debug)]
+/// A sensor `s`, its closest beacon `b` and the distance between them.
+pub struct Line {
+    pub s: Point,
+    pub b: Point,
+
+    pub dist: i64,
+}
+
+impl Line {
+    pub fn new(s: Point, b: Point) -> Self {
+        Self {
+            s,
+            b,
+            dist: s.manhattan(&b),
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split(" ");
+
+        tokens.next();
+        tokens.next();
+
+        let sx = tokens
+            .next()
+            .ok_or(Self::Err::ParseError("expect sensor x token".to_string()))?
+            .split_once("=")
+            .ok_or(Self::Err::ParseError(
+                "except sensor x token to have = sign".to_string(),
+            ))?
+            .1
+            .split_once(",")
+            .ok_or(Self::Err::ParseError(
+                "except sensor x to be followed by , character".to_string(),
+            ))?
+            .0
+            .parse::<i64>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        let sy = tokens
+            .next()
+            .ok_or(Self::Err::ParseError("expect sensor y token".to_string()))?
+            .split_once("=")
+            .ok_or(Self::Err::ParseError(
+                "except sensor y token to have = sign".to_string(),
+            ))?
+            .1
+            .split_once(":")
+            .ok_or(Self::Err::ParseError(
+                "except sensor y to be followed by : character".to_string(),
+            ))?
+            .0
+            .parse::<i64>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        tokens.next();
+        tokens.next();
+        tokens.next();
+        tokens.next();
+
+        let bx = tokens
+            .next()
+            .ok_or(Self::Err::ParseError("expect beacon x token".to_string()))?
+            .split_once("=")
+            .ok_or(Self::Err::ParseError(
+                "except beacon x token to have = sign".to_string(),
+            ))?
+            .1
+            .split_once(",")
+            .ok_or(Self::Err::ParseError(
+                "except beacon x to be followed by , character".to_string(),
+            ))?
+            .0
+            .parse::<i64>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        let by = tokens
+            .next()
+            .ok_or(Self::Err::ParseError("expect beacon y token".to_string()))?
+            .split_once("=")
+            .ok_or(Self::Err::ParseError(
+                "except beacon y token to have = sign".to_string(),
+            ))?
+            .1
+            .parse::<i64>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        return Ok(Line::new(Point::new(sx, sy), Point::new(bx, by)));
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Line> {
+    crate::parsing::lines_to_vec::<Line>(input.as_ref()).expect("to have correct aoc input")
+}
+
+fn union(a: &(i64, i64), b: &(i64, i64)) -> Option<(i64, i64)> {
+    if (a.0 <= b.1 && b.0 <= a.1) || (a.1.abs_diff(b.0) <= 1) || (b.1.abs_diff(a.0) <= 1) {
+        return Some((a.0.min(b.0), a.1.max(b.1)));
+    }
+
+    return None;
+}
+
+/// The sorted, disjoint intervals of row `y_level` covered by the sensors.
+pub fn solution(input: &Vec<Line>, y_level: i64) -> Vec<(i64, i64)> {
+    return input
+        .iter()
+        .filter(|line| (line.s.y - y_level).abs() <= line.dist)
+        .map(|line| {
+            let spread = line.dist - (line.s.y - y_level).abs();
+
+            return (line.s.x - spread, line.s.x + spread);
+        })
+        .sorted()
+        .fold(Vec::new(), |mut acc, int| {
+            if acc.is_empty() {
+                return vec![int];
+            } else if let Some(int) = union(acc.last().unwrap(), &int) {
+                *acc.last_mut().unwrap() = int;
+
+                return acc;
+            } else {
+                acc.push(int);
+
+                return acc;
+            }
+        });
+}
+
+/// The number of positions of row `y_level` where a beacon cannot be.
+pub fn part1(input: &Vec<Line>, y_level: i64) -> String {
+    let beacon_xs = input
+        .iter()
+        .filter(|line| line.b.y == y_level)
+        .map(|line| line.b.x)
+        .unique()
+        .collect::<Vec<i64>>();
+
+    return solution(input, y_level)
+        .into_iter()
+        .map(|int| {
+            let mut total = int.1 - int.0 + 1;
+
+            for x in &beacon_xs {
+                if int.0 <= *x && x <= &int.1 {
+                    total -= 1;
+                }
+            }
+
+            return total;
+        })
+        .sum::<i64>()
+        .to_string();
+}
+
+/// The tuning frequency of the only position, with both coordinates between
+/// 0 and `y_level`, that no sensor covers.
+pub fn part2(input: &Vec<Line>, y_level: i64) -> String {
+    for row in 0..=y_level {
+        let intervals = solution(input, row);
+        if intervals.len() > 1 {
+            let col = intervals.first().unwrap().1 + 1;
+            return (4000000 * col + row).to_string();
+        }
+    }
+
+    "".to_owned()
+}
+
+/// Day 15 for the runner, see [`crate::solution::Solution`].
+pub struct Day15;
+
+impl crate::solution::Solution for Day15 {
+    type Input<'a> = Vec<Line>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 15;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("y_level_1", Y_LEVEL_1))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("y_level_2", Y_LEVEL_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3",
+        );
+
+        assert_eq!(super::part1(&input, 10), "26");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3",
+        );
+
+        assert_eq!(super::part2(&input, 20), "56000011");
+    }
+}
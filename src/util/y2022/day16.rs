@@ -0,0 +1,338 @@
+//! Day 16: Proboscidea Volcanium.
+//!
+//! Every line of the input describes a valve: its flow rate and the tunnels
+//! leading to other valves.
+
+use std::{collections::HashMap, str::FromStr};
+
+use crate::collections::BitSet64;
+use crate::dp::{DenseTable, Table};
+
+/// The time available in part 1; the `minutes_1` parameter.
+pub const MINUTES_1: usize = 30;
+/// The time available in part 2; the `minutes_2` parameter.
+pub const MINUTES_2: usize = 26;
+
+/// A valve: its name, flow rate and the valves its tunnels lead to.
+pub struct Pair {
+    pub name: String,
+    pub node: (usize, Vec<String>),
+}
+
+impl FromStr for Pair {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = s.split(' ');
+
+        tokens.next();
+
+        let name = tokens
+            .next()
+            .ok_or(Self::Err::ParseError(
+                "expect token with name of node".to_string(),
+            ))?
+            .to_string();
+
+        tokens.next();
+        tokens.next();
+
+        let flow = tokens
+            .next()
+            .ok_or(Self::Err::ParseError(
+                "expect token with flow value".to_string(),
+            ))?
+            .split_once('=')
+            .ok_or(Self::Err::ParseError(
+                "expect flow value to have = sign".to_string(),
+            ))?
+            .1
+            .split_once(';')
+            .ok_or(Self::Err::ParseError(
+                "expect token with flow value to end with ; sign".to_string(),
+            ))?
+            .0
+            .parse::<usize>()
+            .map_err(|e| Self::Err::ParseError(e.to_string()))?;
+
+        tokens.next();
+        tokens.next();
+        tokens.next();
+        tokens.next();
+
+        let neighbors = tokens
+            .collect::<String>()
+            .split(',')
+            .map(String::from)
+            .collect();
+
+        return Ok(Pair {
+            name,
+            node: (flow, neighbors),
+        });
+    }
+}
+
+/// The valves worth opening (plus the start `AA`, at index 0) and the
+/// distances between them.
+#[derive(Debug)]
+pub struct Input {
+    pub mapping: Vec<String>,
+    pub dists: Vec<Vec<usize>>,
+    pub flows: Vec<usize>,
+    pub start: usize,
+}
+
+/// Compresses the tunnels into the distances between the valves worth opening.
+pub fn shortest_paths(edges: &HashMap<String, (usize, Vec<String>)>) -> Input {
+    let mut non_zero: Vec<String> = edges
+        .iter()
+        .filter_map(|(k, (flow, _))| {
+            if *flow == 0 {
+                return None;
+            } else {
+                return Some(k.clone());
+            }
+        })
+        .collect();
+    non_zero.insert(0, "AA".to_string());
+
+    let mut dists: HashMap<_, HashMap<_, _>> = edges
+        .clone()
+        .into_keys()
+        .map(|k| {
+            (
+                k,
+                edges.clone().into_keys().map(|k| (k, usize::MAX)).collect(),
+            )
+        })
+        .collect();
+
+    for (u, (_, vs)) in edges {
+        for v in vs {
+            let d = dists.get_mut(u).unwrap().get_mut(v).unwrap();
+            *d = 1;
+        }
+    }
+
+    for u in edges.keys() {
+        let d = dists.get_mut(u).unwrap().get_mut(u).unwrap();
+        *d = 0;
+    }
+
+    for k in edges.keys() {
+        for i in edges.keys() {
+            for j in edges.keys() {
+                if let Some(dn) = dists
+                    .get(i)
+                    .unwrap()
+                    .get(k)
+                    .unwrap()
+                    .checked_add(*dists.get(k).unwrap().get(j).unwrap())
+                {
+                    let d = dists.get_mut(i).unwrap().get_mut(j).unwrap();
+                    if *d > dn {
+                        *d = dn;
+                    }
+                }
+            }
+        }
+    }
+
+    let dists = dists
+        .into_iter()
+        .filter_map(|(u, map)| {
+            if !non_zero.contains(&u) {
+                return None;
+            }
+
+            let dist = map
+                .into_iter()
+                .filter_map(|(v, d)| {
+                    if !non_zero.contains(&u) {
+                        return None;
+                    }
+
+                    return Some((v, d));
+                })
+                .collect::<HashMap<_, _>>();
+
+            let flow = edges.get(&u).unwrap().0;
+            return Some((u, (flow, dist)));
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut result: Vec<Vec<usize>> = vec![vec![0; non_zero.len()]; non_zero.len()];
+    let mut flows: Vec<usize> = vec![0; non_zero.len()];
+    for i in 0..result.len() {
+        let (flow, ns) = dists.get(&non_zero[i]).unwrap();
+        flows[i] = *flow;
+        for j in 0..result[i].len() {
+            result[i][j] = *ns.get(&non_zero[j]).unwrap();
+        }
+    }
+
+    return Input {
+        mapping: non_zero,
+        dists: result,
+        flows,
+        start: 0,
+    };
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Input {
+    let pairs = crate::parsing::lines_to_vec::<Pair>(input.as_ref())
+        .expect("to have correct aoc input")
+        .into_iter()
+        .map(|p| (p.name, p.node))
+        .collect::<HashMap<String, (usize, Vec<String>)>>();
+
+    return shortest_paths(&pairs);
+}
+
+/// The most pressure released after `t` minutes when standing at valve `k`
+/// with the valves of `open` open, at `(t, k, open)`; unreachable states have
+/// no value.
+pub fn compute_dp(input: &Input, minutes: usize) -> DenseTable<i64> {
+    let location_size = input.mapping.len();
+    let mut dp = DenseTable::new(minutes + 1, location_size);
+
+    for k in 0..location_size {
+        let d = input.dists[input.start][k];
+        dp.update((d + 1, k, BitSet64::new().with(k)), 0);
+    }
+
+    for i in 1..=minutes {
+        for j in 0..1 << location_size {
+            let open = BitSet64::from_bits(j);
+            let flow = open.iter().map(|v| input.flows[v]).sum::<usize>() as i64;
+
+            for k in 0..location_size {
+                if let Some(held) = dp.get((i - 1, k, open)) {
+                    dp.update((i, k, open), held + flow);
+                }
+
+                let Some(released) = dp.get((i, k, open)) else {
+                    continue;
+                };
+
+                for l in 0..location_size {
+                    if open.contains(l) {
+                        continue;
+                    }
+
+                    let d = input.dists[k][l];
+                    dp.update(
+                        (i + d + 1, l, open.with(l)),
+                        released + flow * (d as i64 + 1),
+                    );
+                }
+            }
+        }
+    }
+
+    return dp;
+}
+
+/// The most pressure one can release in `minutes`.
+pub fn part1(dp: &DenseTable<i64>, minutes: usize) -> String {
+    return dp
+        .layer(minutes)
+        .map(|(_, _, released)| released)
+        .max()
+        .unwrap_or(0)
+        .to_string();
+}
+
+/// The most pressure one can release in `minutes` together with an elephant.
+pub fn part2(dp: &DenseTable<i64>, minutes: usize) -> String {
+    let best = dp.best_by_set(minutes).into_iter().collect::<Vec<_>>();
+    let mut ans = 0;
+
+    for (i, &(mine, a)) in best.iter().enumerate() {
+        for &(theirs, b) in &best[i..] {
+            if mine.is_disjoint(&theirs) {
+                ans = ans.max(a + b);
+            }
+        }
+    }
+
+    return ans.to_string();
+}
+
+/// Day 16 for the runner, see [`crate::solution::Solution`].
+pub struct Day16;
+
+impl crate::solution::Solution for Day16 {
+    type Input<'a> = DenseTable<i64>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 16;
+
+    fn parse<'a>(input: &'a str, params: &crate::config::Params) -> Self::Input<'a> {
+        let minutes = params
+            .get("minutes_1", MINUTES_1)
+            .max(params.get("minutes_2", MINUTES_2));
+
+        compute_dp(&parse_input(input), minutes)
+    }
+
+    fn part1(dp: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(dp, params.get("minutes_1", MINUTES_1))
+    }
+
+    fn part2(dp: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(dp, params.get("minutes_2", MINUTES_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II",
+        );
+
+        assert_eq!(
+            super::part1(
+                &super::compute_dp(&input, super::MINUTES_1),
+                super::MINUTES_1
+            ),
+            "1651"
+        );
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II",
+        );
+
+        assert_eq!(
+            super::part2(
+                &super::compute_dp(&input, super::MINUTES_2),
+                super::MINUTES_2
+            ),
+            "1707"
+        );
+    }
+}
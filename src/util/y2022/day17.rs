@@ -0,0 +1,387 @@
+//! Day 17: Pyroclastic Flow.
+//!
+//! The input is the pattern of jets of hot gas (`<` and `>`) that push the
+//! falling rocks around the chamber.
+
+use std::{collections::HashSet, str::FromStr};
+
+/// The rocks dropped in part 1; the `drops_1` parameter.
+pub const DROPS_1: usize = 2022;
+/// The rocks dropped in part 2; the `drops_2` parameter.
+pub const DROPS_2: usize = 1000000000000;
+
+/// A jet of gas.
+#[derive(Debug)]
+pub enum Push {
+    Left,
+    Right,
+}
+
+impl FromStr for Push {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "<" => return Ok(Push::Left),
+            ">" => return Ok(Push::Right),
+            c => {
+                return Err(Self::Err::ParseError(format!(
+                    "expect one of < or > but found '{}'",
+                    c
+                )))
+            }
+        }
+    }
+}
+
+impl Push {
+    fn apply(self: &Self, x: i64) -> i64 {
+        match self {
+            Self::Left => x - 1,
+            Self::Right => x + 1,
+        }
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Push> {
+    input
+        .as_ref()
+        .trim()
+        .split("")
+        .filter(|s| !s.is_empty())
+        .map(str::parse::<Push>)
+        .collect::<Result<_, _>>()
+        .expect("to have correct aoc input")
+}
+
+/// A cell of the chamber, ordered by height.
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        return Point { x, y };
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        return self.y.cmp(&other.y);
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Piece {
+    points: Vec<Point>,
+}
+
+impl Piece {
+    fn new_horizontal(p: Point) -> Piece {
+        Piece {
+            points: (0..4)
+                .map(|x| Point::new(p.x + x, p.y))
+                .collect::<Vec<Point>>(),
+        }
+    }
+
+    fn new_plus(p: Point) -> Piece {
+        let mut points = (0..3)
+            .map(|x| Point::new(p.x + x, p.y + 1))
+            .collect::<Vec<_>>();
+        points.push(Point::new(p.x + 1, p.y));
+        points.push(Point::new(p.x + 1, p.y + 2));
+
+        return Piece { points };
+    }
+
+    fn new_l(p: Point) -> Piece {
+        let mut points = (0..3).map(|x| Point::new(p.x + x, p.y)).collect::<Vec<_>>();
+        points.push(Point::new(p.x + 2, p.y + 1));
+        points.push(Point::new(p.x + 2, p.y + 2));
+
+        return Piece { points };
+    }
+
+    fn new_vertical(p: Point) -> Piece {
+        Piece {
+            points: (0..4)
+                .map(|y| Point::new(p.x, p.y + y))
+                .collect::<Vec<Point>>(),
+        }
+    }
+
+    fn new_square(p: Point) -> Piece {
+        let mut points = (0..2).map(|x| Point::new(p.x + x, p.y)).collect::<Vec<_>>();
+        points.push(Point::new(p.x, p.y + 1));
+        points.push(Point::new(p.x + 1, p.y + 1));
+
+        return Piece { points };
+    }
+
+    fn collision(self: &Self, map: &HashSet<Point>) -> bool {
+        for p in &self.points {
+            let pn = Point::new(p.x, p.y - 1);
+            if map.contains(&pn) {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    fn gravity(self: &mut Self) {
+        for p in &mut self.points {
+            p.y -= 1;
+        }
+    }
+
+    fn push(self: &mut Self, map: &HashSet<Point>, push: &Push) {
+        if self.points.iter().all(|p| {
+            let new_x = push.apply(p.x);
+            let pn = Point::new(new_x, p.y);
+
+            return (0..7).contains(&new_x) && !map.contains(&pn);
+        }) {
+            for p in &mut self.points {
+                p.x = push.apply(p.x);
+            }
+        }
+    }
+}
+
+/// The chamber: the rocks that came to rest and the height gained by each drop.
+///
+/// `Display` draws the chamber from the top down.
+#[derive(Debug)]
+pub struct Spawner {
+    piece_counter: usize,
+    tick_counter: usize,
+    pub map: HashSet<Point>,
+    pub top: i64,
+
+    pub heights: Vec<i64>,
+}
+
+impl std::fmt::Display for Spawner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let min_x = 0;
+        let max_x = 6;
+        let min_y = 0;
+        let max_y = self.top + 10;
+
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+
+        let map = self
+            .map
+            .iter()
+            .fold(vec![vec!['.'; width]; height], |mut acc, p| {
+                acc[p.y as usize][p.x as usize] = '#';
+                return acc;
+            });
+
+        let map = map
+            .into_iter()
+            .map(|line| line.into_iter().collect::<String>())
+            .rev()
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        return write!(f, "{}", map);
+    }
+}
+
+impl Default for Spawner {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl Spawner {
+    /// An empty chamber with only its floor.
+    pub fn new() -> Self {
+        let floor = (0..7).map(|x| Point::new(x, 0)).collect::<HashSet<_>>();
+        return Spawner {
+            piece_counter: 0,
+            tick_counter: 0,
+            map: floor,
+            top: 0,
+            heights: Vec::new(),
+        };
+    }
+
+    fn spawn(self: &mut Self) -> Piece {
+        let p = Point::new(2, self.top + 4);
+        let cnt = self.piece_counter;
+
+        self.piece_counter = (self.piece_counter + 1) % 5;
+
+        match cnt {
+            0 => Piece::new_horizontal(p),
+            1 => Piece::new_plus(p),
+            2 => Piece::new_l(p),
+            3 => Piece::new_vertical(p),
+            4 => Piece::new_square(p),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Drops the next rock until it comes to rest, pushed by `input`.
+    pub fn drop(self: &mut Self, input: &Vec<Push>) {
+        let mut piece = self.spawn();
+
+        self.tick_counter %= input.len();
+
+        loop {
+            piece.push(&self.map, &input[self.tick_counter]);
+            self.tick_counter = (self.tick_counter + 1) % input.len();
+
+            if piece.collision(&self.map) {
+                break;
+            }
+            piece.gravity();
+        }
+
+        let prev = self.top;
+        for p in &piece.points {
+            if self.top < p.y {
+                self.top = p.y;
+            }
+        }
+        self.heights.push(self.top - prev);
+
+        self.map.extend(piece.points);
+    }
+
+    /// Finds an `(offset, window)` after which the height gained by each drop
+    /// repeats with period `window`.
+    pub fn find_cycle(self: &Self) -> Option<(usize, usize)> {
+        let heights = &self.heights;
+        for offset in 0..heights.len() {
+            for window in 1..(heights.len() - offset) / 2 {
+                if heights[offset..]
+                    .iter()
+                    .zip(heights[offset..offset + window].iter().cycle())
+                    .all(|(a, b)| a == b)
+                {
+                    return Some((offset, window));
+                }
+            }
+        }
+
+        return None;
+    }
+}
+
+/// The height of the tower after `drops` rocks, simulating every one.
+pub fn part1(input: &Vec<Push>, drops: usize) -> String {
+    let mut spawner = Spawner::new();
+
+    for _ in 0..drops {
+        spawner.drop(input);
+    }
+
+    crate::debug!("the tower after {} rocks:\n{}", drops, spawner);
+
+    return spawner.top.to_string();
+}
+
+/// The top of the tower after `drops` rocks: the empty rows above it and its
+/// last `rows` rows.
+pub fn render(input: &Vec<Push>, drops: usize, rows: usize) -> String {
+    let mut spawner = Spawner::new();
+
+    for _ in 0..drops {
+        spawner.drop(input);
+    }
+
+    return spawner
+        .to_string()
+        .lines()
+        .take(10 + rows)
+        .collect::<Vec<_>>()
+        .join("\n");
+}
+
+/// The height of the tower after `drops` rocks, extrapolated from the cycle of
+/// the height gains.
+pub fn part2(input: &Vec<Push>, drops: usize) -> String {
+    let mut spawner = Spawner::new();
+
+    for _ in 0..5000 {
+        spawner.drop(input);
+    }
+
+    // The cycle may start after so few rocks.
+    if drops <= spawner.heights.len() {
+        return spawner.heights[..drops].iter().sum::<i64>().to_string();
+    }
+
+    if let Some((offset, window)) = spawner.find_cycle() {
+        let init = spawner.heights[..offset].iter().sum::<i64>();
+        let cycle = spawner.heights[offset..offset + window].iter().sum::<i64>();
+
+        let cycles = ((drops - offset) / window) as i64;
+        let end = (drops - offset) % window;
+        let end = spawner.heights[offset..offset + end].iter().sum::<i64>();
+
+        return (init + cycles * cycle + end).to_string();
+    }
+
+    return "sad noises".to_string();
+}
+
+/// Day 17 for the runner, see [`crate::solution::Solution`].
+pub struct Day17;
+
+impl crate::solution::Solution for Day17 {
+    type Input<'a> = Vec<Push>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 17;
+    const HAS_RENDER: bool = true;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("drops_1", DROPS_1))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("drops_2", DROPS_2))
+    }
+
+    fn render(input: &Self::Input<'_>, params: &crate::config::Params) -> Option<String> {
+        Some(render(input, params.get("drops_1", DROPS_1), 40))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
+
+        assert_eq!(super::part1(&input, super::DROPS_1), "3068");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>");
+
+        assert_eq!(super::part2(&input, super::DROPS_2), "1514285714288");
+        assert_eq!(super::part2(&input, super::DROPS_1), "3068");
+        assert_eq!(super::part2(&input, 0), "0");
+    }
+}
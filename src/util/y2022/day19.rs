@@ -0,0 +1,245 @@
+//! Day 19: Not Enough Minerals.
+//!
+//! Every line of the input is a blueprint with the cost of each robot.
+
+use std::str::FromStr;
+
+use crate::dp::Memo;
+
+/// The cost of a robot in ore, clay, obsidian and geodes.
+pub type Recipe = [usize; 4];
+
+/// The time available in part 1; the `minutes_1` parameter.
+pub const MINUTES_1: usize = 24;
+/// The time available in part 2; the `minutes_2` parameter.
+pub const MINUTES_2: usize = 32;
+
+/// The recipes of the ore, clay, obsidian and geode robots.
+#[derive(Debug)]
+pub struct Blueprint {
+    pub recipes: [Recipe; 4],
+}
+
+impl FromStr for Blueprint {
+    type Err = crate::error::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut iter = s.split_ascii_whitespace();
+
+        let ore_recipe = [iter.nth(6).unwrap().parse::<usize>().unwrap(), 0, 0, 0];
+        let clay_recipe = [iter.nth(5).unwrap().parse::<usize>().unwrap(), 0, 0, 0];
+        let obsidian_recipe = [
+            iter.nth(5).unwrap().parse::<usize>().unwrap(),
+            iter.nth(2).unwrap().parse::<usize>().unwrap(),
+            0,
+            0,
+        ];
+        let geode_recipe = [
+            iter.nth(5).unwrap().parse::<usize>().unwrap(),
+            0,
+            iter.nth(2).unwrap().parse::<usize>().unwrap(),
+            0,
+        ];
+        return Ok(Blueprint {
+            recipes: [ore_recipe, clay_recipe, obsidian_recipe, geode_recipe],
+        });
+    }
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<Blueprint> {
+    crate::parsing::lines_to_vec::<Blueprint>(input.as_ref()).expect("correct aoc input")
+}
+
+/// The resources and robots after `steps` minutes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    ores: [usize; 3],
+    robots: [usize; 4],
+    steps: usize,
+}
+
+fn recipe_delay(state: &State, recipe: &Recipe) -> usize {
+    return (0..3)
+        .filter_map(|ore| {
+            if recipe[ore] == 0 {
+                return None;
+            } else if recipe[ore] < state.ores[ore] {
+                return Some(0);
+            } else if state.robots[ore] == 0 {
+                return Some(usize::MAX);
+            } else {
+                return Some((recipe[ore] - state.ores[ore]).div_ceil(state.robots[ore]));
+            }
+        })
+        .max()
+        .unwrap_or(0);
+}
+
+/// The most geodes the robots built from `state` on could open, building a
+/// geode robot every remaining minute.
+fn upper_bound(state: &State, max_steps: usize) -> usize {
+    let left_steps = max_steps - state.steps;
+    return left_steps * left_steps.saturating_sub(1) / 2;
+}
+
+/// The states reached by building each kind of robot next, geode robots
+/// first, with the geodes opened by that robot until the end.
+fn neighbors(
+    bp: &Blueprint,
+    state: &State,
+    max_steps: usize,
+    robots_cap: &[usize; 4],
+) -> Vec<(State, usize)> {
+    return (0..4)
+        .filter_map(|i| {
+            if state.robots[i] == robots_cap[i] {
+                return None;
+            }
+
+            let recipe = bp.recipes[i];
+
+            let delay_steps = recipe_delay(state, &recipe);
+            let new_steps = (state.steps + 1).checked_add(delay_steps)?;
+
+            if new_steps >= max_steps {
+                return None;
+            }
+
+            let mut new_ores = [0; 3];
+            let mut new_robots = state.robots;
+            new_robots[i] += 1;
+            for ore in 0..3 {
+                let ores = state.ores[ore] + state.robots[ore] * (delay_steps + 1) - recipe[ore];
+                // Resources beyond what can still be spent make no difference.
+                let spendable = robots_cap[ore] * (max_steps - new_steps);
+                new_ores[ore] = ores.min(spendable);
+            }
+
+            let geodes = match i {
+                3 => max_steps - new_steps,
+                _ => 0,
+            };
+
+            let new_state = State {
+                steps: new_steps,
+                ores: new_ores,
+                robots: new_robots,
+            };
+
+            return Some((new_state, geodes));
+        })
+        .collect();
+}
+
+/// The most geodes the robots built from `state` on can open, when that is
+/// more than `floor`; otherwise some number no more than `floor`, as the
+/// states that cannot beat it are skipped.
+///
+/// The memo keeps the floor each value was found with: a value above its
+/// floor is exact, and one below it still answers any higher floor.
+fn quality(
+    bp: &Blueprint,
+    state: State,
+    floor: usize,
+    max_steps: usize,
+    robots_cap: &[usize; 4],
+    memo: &mut Memo<State, (usize, usize)>,
+) -> usize {
+    if let Some(&(geodes, found_with)) = memo.get(&state) {
+        if geodes > found_with || found_with <= floor {
+            return geodes;
+        }
+    }
+
+    let mut most = 0;
+    for (n, geodes) in neighbors(bp, &state, max_steps, robots_cap) {
+        most = most.max(geodes);
+
+        let floor = floor.max(most).saturating_sub(geodes);
+        if upper_bound(&n, max_steps) > floor {
+            most = most.max(geodes + quality(bp, n, floor, max_steps, robots_cap, memo));
+        }
+    }
+
+    memo.insert(state, (most, floor));
+
+    return most;
+}
+
+/// The most geodes `bp` can open in `max_steps` minutes.
+pub fn solution(bp: &Blueprint, max_steps: usize) -> usize {
+    let mut robots_cap = [usize::MAX; 4];
+    for i in 0..3 {
+        robots_cap[i] = bp.recipes.iter().map(|r| r[i]).max().unwrap();
+    }
+
+    return quality(
+        bp,
+        State {
+            steps: 0,
+            robots: [1, 0, 0, 0],
+            ores: [0, 0, 0],
+        },
+        0,
+        max_steps,
+        &robots_cap,
+        &mut Memo::new(),
+    );
+}
+
+/// The sum of the quality levels of the blueprints.
+pub fn part1(input: &Vec<Blueprint>, minutes: usize) -> String {
+    (0..input.len())
+        .fold(0, |acc, i| acc + solution(&input[i], minutes) * (i + 1))
+        .to_string()
+}
+
+/// The product of the geodes opened by the first three blueprints.
+pub fn part2(input: &Vec<Blueprint>, minutes: usize) -> String {
+    input
+        .iter()
+        .take(3)
+        .fold(1, |acc, bp| acc * solution(bp, minutes))
+        .to_string()
+}
+
+/// Day 19 for the runner, see [`crate::solution::Solution`].
+pub struct Day19;
+
+impl crate::solution::Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 19;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part1(input, params.get("minutes_1", MINUTES_1))
+    }
+
+    fn part2(input: &Self::Input<'_>, params: &crate::config::Params) -> String {
+        part2(input, params.get("minutes_2", MINUTES_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.");
+
+        assert_eq!(super::part1(&input, super::MINUTES_1), "33");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.");
+
+        assert_eq!(super::part2(&input, super::MINUTES_2), "3472");
+    }
+}
@@ -0,0 +1,132 @@
+//! Day 20: Grove Positioning System.
+//!
+//! The input is the encrypted file, one number per line.
+
+use std::collections::HashMap;
+
+use crate::collections::Sequence;
+
+pub fn parse_input(input: impl AsRef<str>) -> Vec<isize> {
+    input
+        .as_ref()
+        .lines()
+        .map(|line| line.parse::<isize>())
+        .collect::<Result<_, _>>()
+        .expect("to have correct aoc input")
+}
+
+/// Moves every number of `order` in `xs` as far as its value, in order.
+///
+/// The numbers are paired with their original index to tell duplicates apart.
+pub fn mix(xs: &mut Vec<(usize, isize)>, order: &Vec<(usize, isize)>) {
+    let mut sequence = Sequence::new();
+    let ids = xs
+        .iter()
+        .map(|&x| (x, sequence.push(x)))
+        .collect::<HashMap<_, _>>();
+
+    for x in order {
+        let i = sequence.position(ids[x]).unwrap();
+        let im = (i as isize + x.1).rem_euclid((xs.len() - 1) as isize) as usize;
+
+        sequence.relocate(i, im);
+    }
+
+    *xs = sequence.iter().copied().collect();
+}
+
+/// The sum of the grove coordinates after a single mix.
+pub fn part1(input: &Vec<isize>) -> String {
+    let input: Vec<(usize, isize)> = input.iter().enumerate().map(|x| (x.0, *x.1)).collect();
+    let mut xs = input.clone();
+
+    mix(&mut xs, &input);
+
+    let zero = xs
+        .iter()
+        .position(|x| x.1 == 0)
+        .expect("to have at least one zero in input");
+    return [1000, 2000, 3000]
+        .iter()
+        .fold(0, |acc, i| acc + xs[(zero + i).rem_euclid(xs.len())].1)
+        .to_string();
+}
+
+/// The sum of the grove coordinates after applying the decryption key and
+/// mixing ten times.
+pub fn part2(input: &Vec<isize>) -> String {
+    let input: Vec<(usize, isize)> = input
+        .iter()
+        .enumerate()
+        .map(|x| (x.0, x.1 * 811589153))
+        .collect();
+    let mut xs = input.clone();
+
+    for _ in 0..10 {
+        mix(&mut xs, &input);
+    }
+
+    let zero = xs
+        .iter()
+        .position(|x| x.1 == 0)
+        .expect("to have at least one zero in input");
+    return [1000, 2000, 3000]
+        .iter()
+        .fold(0, |acc, i| acc + xs[(zero + i).rem_euclid(xs.len())].1)
+        .to_string();
+}
+
+/// Day 20 for the runner, see [`crate::solution::Solution`].
+pub struct Day20;
+
+impl crate::solution::Solution for Day20 {
+    type Input<'a> = Vec<isize>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 20;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "1
+2
+-3
+3
+-2
+0
+4",
+        );
+
+        assert_eq!(super::part1(&input), "3");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "1
+2
+-3
+3
+-2
+0
+4",
+        );
+
+        assert_eq!(super::part2(&input), "1623178306");
+    }
+}
@@ -0,0 +1,210 @@
+//! Day 21: Monkey Math.
+//!
+//! Every line of the input is a monkey that yells a number or the result of
+//! an operation on the numbers of two other monkeys.
+
+use std::collections::HashMap;
+
+use crate::math::exact_div;
+
+/// The job of a monkey, referring to other monkeys by name.
+#[derive(Debug)]
+pub enum Operation<'a> {
+    Number(isize),
+    Add(&'a str, &'a str),
+    Sub(&'a str, &'a str),
+    Mul(&'a str, &'a str),
+    Div(&'a str, &'a str),
+}
+
+impl<'a> TryFrom<&'a str> for Operation<'a> {
+    type Error = crate::error::Error;
+
+    fn try_from(s: &'a str) -> Result<Operation<'a>, <Operation<'a> as TryFrom<&'a str>>::Error> {
+        if let Ok(num) = s.parse::<isize>() {
+            return Ok(Operation::Number(num));
+        } else if let Some((lhs, rhs)) = s.split_once(" + ") {
+            return Ok(Operation::Add(lhs, rhs));
+        } else if let Some((lhs, rhs)) = s.split_once(" - ") {
+            return Ok(Operation::Sub(lhs, rhs));
+        } else if let Some((lhs, rhs)) = s.split_once(" * ") {
+            return Ok(Operation::Mul(lhs, rhs));
+        } else if let Some((lhs, rhs)) = s.split_once(" / ") {
+            return Ok(Operation::Div(lhs, rhs));
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+/// The job of every monkey, by name.
+pub type Input<'a> = HashMap<&'a str, Operation<'a>>;
+
+pub fn parse_input<'a>(input: &'a str) -> Input<'a> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some((lhs, rhs)) = line.split_once(": ") {
+                if let Ok(op) = Operation::try_from(rhs) {
+                    return Some((lhs, op));
+                }
+            }
+
+            return None;
+        })
+        .collect::<Option<HashMap<_, _>>>()
+        .expect("to have correct aoc input")
+}
+
+/// The number yelled by `node`; `None` when a monkey overflows or divides
+/// with a remainder.
+pub fn dfs(node: &str, input: &Input) -> Option<isize> {
+    let op = input.get(node).expect("to have an entry for each node");
+
+    match op {
+        Operation::Number(num) => Some(*num),
+        Operation::Add(lhs, rhs) => dfs(lhs, input)?.checked_add(dfs(rhs, input)?),
+        Operation::Sub(lhs, rhs) => dfs(lhs, input)?.checked_sub(dfs(rhs, input)?),
+        Operation::Mul(lhs, rhs) => dfs(lhs, input)?.checked_mul(dfs(rhs, input)?),
+        Operation::Div(lhs, rhs) => exact_div(dfs(lhs, input)?, dfs(rhs, input)?),
+    }
+}
+
+/// The number yelled by `root`.
+pub fn part1(input: &Input) -> String {
+    dfs("root", input)
+        .expect("the monkeys to yell integers")
+        .to_string()
+}
+
+fn reverse_answer(answer: isize, node: &str, input: &Input) -> Option<isize> {
+    if node == "humn" {
+        return Some(answer);
+    }
+
+    let op = input.get(node).expect("to have an entry for each node");
+
+    let (lhs, rhs) = match op {
+        Operation::Number(_) => return None,
+        Operation::Add(lhs, rhs) => (lhs, rhs),
+        Operation::Sub(lhs, rhs) => (lhs, rhs),
+        Operation::Mul(lhs, rhs) => (lhs, rhs),
+        Operation::Div(lhs, rhs) => (lhs, rhs),
+    };
+
+    // `humn` is on the side whose answer can be reversed; the other side has
+    // to yell a number that makes the operation come out exactly.
+    let lhs_humn = dfs(rhs, input).and_then(|rhs_ans| {
+        let answer = match op {
+            Operation::Number(_) => unreachable!(),
+            Operation::Add(..) => answer.checked_sub(rhs_ans)?,
+            Operation::Sub(..) => answer.checked_add(rhs_ans)?,
+            Operation::Mul(..) => exact_div(answer, rhs_ans)?,
+            Operation::Div(..) => answer.checked_mul(rhs_ans)?,
+        };
+        return reverse_answer(answer, lhs, input);
+    });
+
+    let rhs_humn = dfs(lhs, input).and_then(|lhs_ans| {
+        let answer = match op {
+            Operation::Number(_) => unreachable!(),
+            Operation::Add(..) => answer.checked_sub(lhs_ans)?,
+            Operation::Sub(..) => lhs_ans.checked_sub(answer)?,
+            Operation::Mul(..) => exact_div(answer, lhs_ans)?,
+            Operation::Div(..) => exact_div(lhs_ans, answer)?,
+        };
+        return reverse_answer(answer, rhs, input);
+    });
+
+    return lhs_humn.or(rhs_humn);
+}
+
+/// The number `humn` has to yell for both operands of `root` to be equal.
+pub fn part2(input: &Input) -> String {
+    let root = input.get("root").expect("to have the root monkey");
+
+    let (lhs, rhs) = match root {
+        Operation::Number(_) => unreachable!(),
+        Operation::Add(lhs, rhs) => (lhs, rhs),
+        Operation::Sub(lhs, rhs) => (lhs, rhs),
+        Operation::Mul(lhs, rhs) => (lhs, rhs),
+        Operation::Div(lhs, rhs) => (lhs, rhs),
+    };
+
+    return dfs(rhs, input)
+        .and_then(|rhs_ans| reverse_answer(rhs_ans, lhs, input))
+        .or_else(|| dfs(lhs, input).and_then(|lhs_ans| reverse_answer(lhs_ans, rhs, input)))
+        .expect("one of them to work")
+        .to_string();
+}
+
+/// Day 21 for the runner, see [`crate::solution::Solution`].
+pub struct Day21;
+
+impl crate::solution::Solution for Day21 {
+    type Input<'a> = Input<'a>;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 21;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32",
+        );
+
+        assert_eq!(super::part1(&input), "152");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32",
+        );
+
+        assert_eq!(super::part2(&input), "301");
+    }
+}
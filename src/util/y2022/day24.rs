@@ -0,0 +1,284 @@
+//! Day 24: Blizzard Basin.
+//!
+//! The input is a map of the valley with the blizzards in it.
+
+use itertools::Itertools;
+use pathfinding::prelude::astar;
+use std::collections::HashSet;
+
+/// A position in the valley as `(x, y)`.
+pub type Point = (i32, i32);
+
+/// The moves available to the expedition, waiting last.
+pub const DIR_5: [Point; 5] = [(0, -1), (0, 1), (-1, 0), (1, 0), (0, 0)];
+/// The blizzards that move along the first four of [`DIR_5`].
+pub const DIR_C: [char; 4] = ['^', 'v', '<', '>'];
+
+fn step(pos: Point, dir: Point) -> Point {
+    (pos.0 + dir.0, pos.1 + dir.1)
+}
+
+/// The valley, with the blizzards of every minute of a period.
+#[derive(Debug)]
+pub struct Input {
+    /// The blizzard positions for each direction of [`DIR_C`], every minute
+    /// until they are back where they started.
+    pub blizzards: Vec<Vec<HashSet<(i32, i32)>>>,
+    /// The minutes after which every blizzard is back where it started.
+    pub period: usize,
+    pub width: i32,
+    pub height: i32,
+    pub source: (i32, i32),
+    pub dest: (i32, i32),
+}
+
+/// The positions of the blizzards moving along `dir` for `minutes` minutes.
+fn ticks(
+    blizzard: &HashSet<(i32, i32)>,
+    dir: Point,
+    width: i32,
+    height: i32,
+    minutes: usize,
+) -> Vec<HashSet<Point>> {
+    let mut blizzards = vec![blizzard.clone()];
+    while blizzards.len() < minutes {
+        let n_blizzard = blizzards[blizzards.len() - 1]
+            .iter()
+            .map(|&pos| {
+                let mut n_pos = step(pos, dir);
+                if n_pos.0 < 1 {
+                    n_pos.0 = width - 1;
+                }
+                if n_pos.1 < 1 {
+                    n_pos.1 = height - 1;
+                }
+                if n_pos.0 > width - 1 {
+                    n_pos.0 = 1;
+                }
+                if n_pos.1 > height - 1 {
+                    n_pos.1 = 1;
+                }
+
+                return n_pos;
+            })
+            .collect();
+
+        blizzards.push(n_blizzard);
+    }
+
+    return blizzards;
+}
+
+pub fn parse_input(input: impl AsRef<str>) -> Input {
+    let lines: Vec<_> = input.as_ref().lines().collect();
+
+    let height = lines.len() - 1;
+    let width = lines[0].len() - 1;
+
+    let source = lines[0]
+        .chars()
+        .position(|c| c == '.')
+        .expect("to have an entry point");
+    let dest = lines[height]
+        .chars()
+        .position(|c| c == '.')
+        .expect("to have an exit point");
+
+    let blizzards: Vec<HashSet<(i32, i32)>> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars().enumerate().filter_map(move |(x, c)| {
+                let dir = DIR_C.iter().position(|&d| d == c)?;
+                return Some((dir, (x as i32, y as i32)));
+            })
+        })
+        .into_group_map()
+        .into_iter()
+        .sorted()
+        .map(|(_, v)| HashSet::from_iter(v))
+        .collect();
+
+    // The blizzards wrap around the inside of the walls, so the vertical ones
+    // repeat every `height - 1` minutes and the horizontal ones every
+    // `width - 1`.
+    let blizzards: Vec<_> = (0..DIR_C.len())
+        .map(|dir| {
+            let minutes = match DIR_5[dir] {
+                (0, _) => height - 1,
+                _ => width - 1,
+            };
+            ticks(
+                &blizzards[dir],
+                DIR_5[dir],
+                width as i32,
+                height as i32,
+                minutes,
+            )
+        })
+        .collect();
+
+    return Input {
+        blizzards,
+        period: crate::math::lcm(width - 1, height - 1).expect("the valley to be small"),
+        width: width as i32,
+        height: height as i32,
+        source: (source as i32, 0),
+        dest: (dest as i32, height as i32),
+    };
+}
+
+/// A position at some minute, modulo the period of the blizzards, so that the
+/// search does not revisit the same situation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    pos: (i32, i32),
+    minute: usize,
+}
+
+fn valid(input: &Input, state: &State) -> bool {
+    0 < state.pos.0
+        && state.pos.0 < input.width
+        && 0 < state.pos.1
+        && state.pos.1 < input.height
+        && input
+            .blizzards
+            .iter()
+            .all(|bs| !bs[state.minute % bs.len()].contains(&state.pos))
+}
+
+fn successors(input: &Input, state: &State) -> Vec<(State, i32)> {
+    DIR_5
+        .iter()
+        .filter_map(|&d| {
+            let n_state = State {
+                pos: (state.pos.0 + d.0, state.pos.1 + d.1),
+                minute: (state.minute + 1) % input.period,
+            };
+
+            if valid(input, &n_state) || n_state.pos == input.dest || n_state.pos == input.source {
+                return Some((n_state, 1));
+            } else {
+                return None;
+            }
+        })
+        .collect()
+}
+
+fn manhattan(a: Point, b: Point) -> i32 {
+    return (a.0 - b.0).abs() + (a.1 - b.1).abs();
+}
+
+/// The fewest minutes to reach the goal.
+pub fn part1(input: &Input) -> String {
+    let state = State {
+        pos: input.source,
+        minute: 0,
+    };
+
+    let (_, cost) = astar(
+        &state,
+        |s| successors(input, s),
+        |s| manhattan(s.pos, input.dest),
+        |s| s.pos == input.dest,
+    )
+    .expect("to have solution");
+
+    return cost.to_string();
+}
+
+/// The fewest minutes to reach the goal, go back for the snacks and reach
+/// the goal again.
+pub fn part2(input: &Input) -> String {
+    let state = State {
+        pos: input.source,
+        minute: 0,
+    };
+
+    let (states, cost1) = astar(
+        &state,
+        |s| successors(input, s),
+        |s| manhattan(s.pos, input.dest),
+        |s| s.pos == input.dest,
+    )
+    .expect("to have solution");
+
+    let state = State {
+        pos: input.dest,
+        minute: states[states.len() - 1].minute,
+    };
+    let (states, cost2) = astar(
+        &state,
+        |s| successors(input, s),
+        |s| manhattan(s.pos, input.source),
+        |s| s.pos == input.source,
+    )
+    .expect("to have solution");
+
+    let state = State {
+        pos: input.source,
+        minute: states[states.len() - 1].minute,
+    };
+    let (_, cost3) = astar(
+        &state,
+        |s| successors(input, s),
+        |s| manhattan(s.pos, input.dest),
+        |s| s.pos == input.dest,
+    )
+    .expect("to have solution");
+
+    return (cost1 + cost2 + cost3).to_string();
+}
+
+/// Day 24 for the runner, see [`crate::solution::Solution`].
+pub struct Day24;
+
+impl crate::solution::Solution for Day24 {
+    type Input<'a> = Input;
+
+    const YEAR: u32 = 2022;
+    const DAY: u32 = 24;
+
+    fn parse<'a>(input: &'a str, _: &crate::config::Params) -> Self::Input<'a> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>, _: &crate::config::Params) -> String {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn part1_example1() {
+        let input = super::parse_input(
+            "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+        );
+
+        assert_eq!(super::part1(&input), "18");
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = super::parse_input(
+            "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#",
+        );
+
+        assert_eq!(super::part2(&input), "54");
+    }
+}
@@ -0,0 +1,60 @@
+//! The puzzles of Advent of Code 2022, one module per day.
+//!
+//! Every module exposes `parse_input`, `part1` and `part2` along with its domain
+//! types, and a `DayNN` type implementing [`crate::solution::Solution`] that the
+//! `dayNN` binaries hand to [`crate::runner::main`].
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// The days of the event, for [`crate::registry`].
+pub const DAYS: &[crate::registry::Entry] = &[
+    crate::registry::Entry::of::<day01::Day01>(),
+    crate::registry::Entry::of::<day02::Day02>(),
+    crate::registry::Entry::of::<day03::Day03>(),
+    crate::registry::Entry::of::<day04::Day04>(),
+    crate::registry::Entry::of::<day05::Day05>(),
+    crate::registry::Entry::of::<day06::Day06>(),
+    crate::registry::Entry::of::<day07::Day07>(),
+    crate::registry::Entry::of::<day08::Day08>(),
+    crate::registry::Entry::of::<day09::Day09>(),
+    crate::registry::Entry::of::<day10::Day10>(),
+    crate::registry::Entry::of::<day11::Day11>(),
+    crate::registry::Entry::of::<day12::Day12>(),
+    crate::registry::Entry::of::<day13::Day13>(),
+    crate::registry::Entry::of::<day14::Day14>(),
+    crate::registry::Entry::of::<day15::Day15>(),
+    crate::registry::Entry::of::<day16::Day16>(),
+    crate::registry::Entry::of::<day17::Day17>(),
+    crate::registry::Entry::of::<day18::Day18>(),
+    crate::registry::Entry::of::<day19::Day19>(),
+    crate::registry::Entry::of::<day20::Day20>(),
+    crate::registry::Entry::of::<day21::Day21>(),
+    crate::registry::Entry::of::<day22::Day22>(),
+    crate::registry::Entry::of::<day23::Day23>(),
+    crate::registry::Entry::of::<day24::Day24>(),
+    crate::registry::Entry::of::<day25::Day25>(),
+];